@@ -0,0 +1,124 @@
+use spacetimedb::{Identity, ReducerContext, Table, Timestamp};
+
+/// An identity allowed to call the world-editing reducers.
+///
+/// Game clients connect to the same database as the editor, so every world-editing
+/// reducer checks `ctx.sender` against this table before touching `world_object`.
+#[spacetimedb::table(name = editor_identity, public)]
+pub struct EditorIdentity {
+    #[primary_key]
+    pub identity: Identity,
+
+    /// Admins can grant and revoke editor rights for other identities.
+    pub is_admin: bool,
+
+    /// Who granted the rights. Equal to `identity` for the admin seeded at `init`.
+    pub granted_by: Identity,
+
+    pub granted_at: Timestamp,
+}
+
+/// Returns an error unless the caller is an editor.
+pub(crate) fn ensure_editor(ctx: &ReducerContext) -> Result<(), String> {
+    if ctx
+        .db
+        .editor_identity()
+        .identity()
+        .find(&ctx.sender)
+        .is_none()
+    {
+        return Err(format!("{} is not allowed to edit the world", ctx.sender));
+    }
+    Ok(())
+}
+
+/// Returns an error unless the caller is an editor admin.
+pub(crate) fn ensure_admin(ctx: &ReducerContext) -> Result<(), String> {
+    match ctx.db.editor_identity().identity().find(&ctx.sender) {
+        Some(editor) if editor.is_admin => Ok(()),
+        _ => Err(format!("{} is not an editor admin", ctx.sender)),
+    }
+}
+
+/// Make the identity publishing the database its first editor admin.
+///
+/// Call this from the module's `init` reducer, where `ctx.sender` is the publisher. It runs
+/// again after `publish --delete-data`, so a wiped database gets its admin back. Clients can't
+/// claim admin rights themselves; every other editor is added with `grant_editor`.
+pub fn seed_editor_admin(ctx: &ReducerContext) {
+    if ctx
+        .db
+        .editor_identity()
+        .identity()
+        .find(&ctx.sender)
+        .is_some()
+    {
+        return;
+    }
+
+    log::info!("{} is the editor admin of this database", ctx.sender);
+    ctx.db.editor_identity().insert(EditorIdentity {
+        identity: ctx.sender,
+        is_admin: true,
+        granted_by: ctx.sender,
+        granted_at: ctx.timestamp,
+    });
+}
+
+/// Grant (or update) editor rights for `identity`. Admin only.
+#[spacetimedb::reducer]
+pub fn grant_editor(
+    ctx: &ReducerContext,
+    identity: Identity,
+    is_admin: bool,
+) -> Result<(), String> {
+    ensure_admin(ctx)?;
+
+    let editor = EditorIdentity {
+        identity,
+        is_admin,
+        granted_by: ctx.sender,
+        granted_at: ctx.timestamp,
+    };
+
+    if let Some(existing) = ctx.db.editor_identity().identity().find(&identity) {
+        if existing.is_admin && !is_admin {
+            ensure_other_admin_exists(ctx, identity)?;
+        }
+        ctx.db.editor_identity().identity().update(editor);
+    } else {
+        ctx.db.editor_identity().insert(editor);
+    }
+    Ok(())
+}
+
+/// Revoke editor rights from `identity`. Admin only.
+#[spacetimedb::reducer]
+pub fn revoke_editor(ctx: &ReducerContext, identity: Identity) -> Result<(), String> {
+    ensure_admin(ctx)?;
+
+    let Some(existing) = ctx.db.editor_identity().identity().find(&identity) else {
+        return Err(format!("{} is not an editor", identity));
+    };
+
+    if existing.is_admin {
+        ensure_other_admin_exists(ctx, identity)?;
+    }
+
+    ctx.db.editor_identity().identity().delete(&identity);
+    Ok(())
+}
+
+/// Keep at least one admin around, otherwise nobody could grant rights again.
+fn ensure_other_admin_exists(ctx: &ReducerContext, identity: Identity) -> Result<(), String> {
+    let has_other_admin = ctx
+        .db
+        .editor_identity()
+        .iter()
+        .any(|editor| editor.is_admin && editor.identity != identity);
+
+    if !has_other_admin {
+        return Err("Cannot remove the last editor admin".into());
+    }
+    Ok(())
+}
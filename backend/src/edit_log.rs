@@ -0,0 +1,80 @@
+//! Audit log of world edits: who changed which object, when, and from what to what.
+//!
+//! Every world-editing reducer appends one row per object it touches, in the same transaction
+//! as the edit, so a refused or failed edit never shows up in the log. World imports replace
+//! every row at once and are logged as a single entry with `object_id == 0`.
+
+use crate::{WorldObject, auth::ensure_admin};
+use spacetimedb::{Identity, ReducerContext, Table, Timestamp};
+
+/// One change to one world object.
+#[spacetimedb::table(name = edit_log, public)]
+pub struct EditLog {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+
+    pub at: Timestamp,
+
+    /// The identity that called the reducer.
+    #[index(btree)]
+    pub author: Identity,
+
+    /// Name of the reducer that made the change, e.g. `"move_object"`.
+    pub reducer: String,
+
+    /// The object that changed. `0` for edits that replace the whole world (imports).
+    #[index(btree)]
+    pub object_id: u64,
+
+    /// The row before the change; `None` if it didn't exist yet.
+    pub before: Option<WorldObject>,
+
+    /// The row after the change; `None` if it was deleted.
+    pub after: Option<WorldObject>,
+}
+
+/// Record a change made by the current reducer call.
+pub(crate) fn log_edit(
+    ctx: &ReducerContext,
+    reducer: &str,
+    object_id: u64,
+    before: Option<WorldObject>,
+    after: Option<WorldObject>,
+) {
+    ctx.db.edit_log().insert(EditLog {
+        id: 0,
+        at: ctx.timestamp,
+        author: ctx.sender,
+        reducer: reducer.to_string(),
+        object_id,
+        before,
+        after,
+    });
+}
+
+/// Delete every log entry made before `older_than`. Admin only.
+#[spacetimedb::reducer]
+pub fn prune_edit_log(ctx: &ReducerContext, older_than: Timestamp) -> Result<(), String> {
+    ensure_admin(ctx)?;
+
+    let stale: Vec<u64> = ctx
+        .db
+        .edit_log()
+        .iter()
+        .filter(|entry| entry.at < older_than)
+        .map(|entry| entry.id)
+        .collect();
+
+    for id in &stale {
+        ctx.db.edit_log().id().delete(id);
+    }
+
+    log::info!(
+        "{} pruned {} edit log entries older than {}",
+        ctx.sender,
+        stale.len(),
+        older_than
+    );
+    Ok(())
+}
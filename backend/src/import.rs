@@ -0,0 +1,231 @@
+//! Replacing the whole world from an editor-side backup, keeping row ids.
+//!
+//! Worlds can be far larger than a single reducer argument should be, so an import is staged:
+//! 1. `begin_world_import` opens a staging session for the caller,
+//! 2. `append_world_import_chunk` adds rows to the caller's staging area, in order,
+//! 3. `commit_world_import` swaps the staged rows in for every `world_object` row.
+//!
+//! Live rows are only touched by the commit, which runs in a single transaction, so an
+//! import that fails or is abandoned halfway never replaces anything. The commit is refused
+//! while another editor holds a lock on any live object.
+
+use crate::{
+    WorldObject,
+    auth::ensure_editor,
+    edit_log::log_edit,
+    lock::{drop_lock, ensure_unlocked},
+    preview::drop_preview,
+    reducers::is_valid_relative_asset_path,
+    reserve_object_ids_up_to,
+    transform::check_object_transform,
+    types::AssetKind,
+    world_object,
+};
+use spacetimedb::{Identity, ReducerContext, Table, Timestamp};
+
+/// An import in progress. Each editor identity can have at most one.
+#[spacetimedb::table(name = world_import)]
+pub struct WorldImport {
+    #[primary_key]
+    owner: Identity,
+
+    /// How many rows the commit expects to find staged.
+    expected_rows: u64,
+
+    /// How many rows have been staged so far.
+    staged_rows: u64,
+
+    /// Index of the next chunk `append_world_import_chunk` accepts.
+    next_chunk: u32,
+
+    started_at: Timestamp,
+}
+
+/// A row waiting in an import's staging area.
+#[spacetimedb::table(name = world_import_row)]
+pub struct WorldImportRow {
+    #[primary_key]
+    #[auto_inc]
+    staging_id: u64,
+
+    #[index(btree)]
+    owner: Identity,
+
+    object: WorldObject,
+}
+
+/// Start a new import of `expected_rows` rows, discarding any unfinished import by the caller.
+#[spacetimedb::reducer]
+pub fn begin_world_import(ctx: &ReducerContext, expected_rows: u64) -> Result<(), String> {
+    ensure_editor(ctx)?;
+
+    discard_import(ctx, ctx.sender);
+    ctx.db.world_import().insert(WorldImport {
+        owner: ctx.sender,
+        expected_rows,
+        staged_rows: 0,
+        next_chunk: 0,
+        started_at: ctx.timestamp,
+    });
+    Ok(())
+}
+
+/// Stage the next chunk of rows. Chunks must arrive in order, starting at 0.
+#[spacetimedb::reducer]
+pub fn append_world_import_chunk(
+    ctx: &ReducerContext,
+    chunk_index: u32,
+    objects: Vec<WorldObject>,
+) -> Result<(), String> {
+    ensure_editor(ctx)?;
+
+    let Some(mut import) = ctx.db.world_import().owner().find(&ctx.sender) else {
+        return Err("No world import in progress; call begin_world_import first".into());
+    };
+
+    if chunk_index != import.next_chunk {
+        return Err(format!(
+            "Expected import chunk {}, got {}",
+            import.next_chunk, chunk_index
+        ));
+    }
+
+    let staged_rows = import.staged_rows + objects.len() as u64;
+    if staged_rows > import.expected_rows {
+        return Err(format!(
+            "Import chunk {} exceeds the {} row(s) announced in begin_world_import",
+            chunk_index, import.expected_rows
+        ));
+    }
+
+    for object in objects {
+        let object = validate_import_row(object)?;
+        ctx.db.world_import_row().insert(WorldImportRow {
+            staging_id: 0,
+            owner: ctx.sender,
+            object,
+        });
+    }
+
+    import.staged_rows = staged_rows;
+    import.next_chunk += 1;
+    ctx.db.world_import().owner().update(import);
+    Ok(())
+}
+
+/// Replace every `world_object` row with the caller's staged rows, keeping their ids.
+#[spacetimedb::reducer]
+pub fn commit_world_import(ctx: &ReducerContext) -> Result<(), String> {
+    ensure_editor(ctx)?;
+
+    let Some(import) = ctx.db.world_import().owner().find(&ctx.sender) else {
+        return Err("No world import in progress; call begin_world_import first".into());
+    };
+
+    let objects: Vec<WorldObject> = ctx
+        .db
+        .world_import_row()
+        .owner()
+        .filter(&ctx.sender)
+        .map(|row| row.object)
+        .collect();
+
+    if objects.len() as u64 != import.expected_rows {
+        return Err(format!(
+            "Import is incomplete: {} of {} row(s) staged",
+            objects.len(),
+            import.expected_rows
+        ));
+    }
+
+    discard_import(ctx, ctx.sender);
+    replace_world_objects(ctx, objects)
+}
+
+/// Drop the caller's unfinished import, if any.
+#[spacetimedb::reducer]
+pub fn cancel_world_import(ctx: &ReducerContext) -> Result<(), String> {
+    ensure_editor(ctx)?;
+
+    discard_import(ctx, ctx.sender);
+    Ok(())
+}
+
+fn discard_import(ctx: &ReducerContext, owner: Identity) {
+    ctx.db.world_import_row().owner().delete(&owner);
+    ctx.db.world_import().owner().delete(&owner);
+}
+
+fn validate_import_row(mut object: WorldObject) -> Result<WorldObject, String> {
+    if object.id == 0 {
+        return Err("Imported objects must have a non-zero ID".into());
+    }
+
+    if let AssetKind::Path(path) = object.asset {
+        if !is_valid_relative_asset_path(&path) {
+            return Err(format!(
+                "Invalid asset path for object with ID {}: {:?}",
+                object.id, path
+            ));
+        }
+        object.asset = AssetKind::Path(path.replace('\\', "/"));
+    }
+    if let AssetKind::PrimitiveShape(shape) = &object.asset {
+        shape.validate().map_err(|err| {
+            format!(
+                "Invalid primitive shape for object with ID {}: {}",
+                object.id, err
+            )
+        })?;
+    }
+
+    check_object_transform(&mut object).map_err(|err| {
+        format!(
+            "Invalid transform for object with ID {}: {}",
+            object.id, err
+        )
+    })?;
+    object.collision_shape.validate().map_err(|err| {
+        format!(
+            "Invalid collision shape for object with ID {}: {}",
+            object.id, err
+        )
+    })?;
+
+    Ok(object)
+}
+
+fn replace_world_objects(ctx: &ReducerContext, objects: Vec<WorldObject>) -> Result<(), String> {
+    let existing: Vec<u64> = ctx.db.world_object().iter().map(|row| row.id).collect();
+    // An import must not overwrite objects another editor is working on.
+    for id in &existing {
+        ensure_unlocked(ctx, *id)?;
+    }
+    // Locks and previews describe the replaced rows, even where an imported row keeps the id.
+    for id in existing {
+        ctx.db.world_object().id().delete(&id);
+        drop_lock(ctx, id);
+        drop_preview(ctx, id);
+    }
+
+    let count = objects.len();
+    let mut max_id = 0;
+    for object in objects {
+        max_id = max_id.max(object.id);
+        let id = object.id;
+        ctx.db
+            .world_object()
+            .try_insert(object)
+            .map_err(|_| format!("Duplicate object ID in import: {}", id))?;
+    }
+
+    reserve_object_ids_up_to(ctx, max_id);
+    // Logging every replaced row would copy the whole world into the log; one entry marks the import.
+    log_edit(ctx, "commit_world_import", 0, None, None);
+    log::info!(
+        "{} replaced the world with {} imported object(s)",
+        ctx.sender,
+        count
+    );
+    Ok(())
+}
@@ -1,15 +1,26 @@
+mod auth;
+mod edit_log;
+mod import;
+mod lock;
+mod presence;
+mod preview;
 mod reducers;
 mod shape;
+mod transform;
 mod types;
 
+pub use auth::seed_editor_admin;
 use shape::CollisionShape;
+use spacetimedb::{ReducerContext, Table};
 use types::{AssetKind, Quat, Vec3};
 
 /// The stored reference to an object that exists in the world.
 #[spacetimedb::table(name = world_object, public)]
+#[derive(Clone)]
 pub struct WorldObject {
+    /// Assigned by `allocate_object_id` rather than `#[auto_inc]`, so imports can keep the
+    /// ids from a snapshot without colliding with ids handed out later.
     #[primary_key]
-    #[auto_inc]
     pub id: u64,
 
     /// The thing that should be rendered into the world.
@@ -29,3 +40,58 @@ pub struct WorldObject {
     /// for physics calculations and hit detection.
     pub collision_shape: CollisionShape,
 }
+
+/// The next id `allocate_object_id` hands out. Holds at most one row, with `key == 0`.
+#[spacetimedb::table(name = world_object_id_counter)]
+pub struct WorldObjectIdCounter {
+    #[primary_key]
+    key: u8,
+    next_id: u64,
+}
+
+/// Hand out a fresh `WorldObject` id. Ids start at 1 and are never reused.
+pub(crate) fn allocate_object_id(ctx: &ReducerContext) -> u64 {
+    let id = next_object_id(ctx);
+    set_next_object_id(ctx, id + 1);
+    id
+}
+
+/// Make sure ids handed out later can't collide with rows that were inserted with explicit ids.
+pub(crate) fn reserve_object_ids_up_to(ctx: &ReducerContext, max_id: u64) {
+    if next_object_id(ctx) <= max_id {
+        set_next_object_id(ctx, max_id + 1);
+    }
+}
+
+/// Drop the state a disconnecting client leaves behind.
+#[spacetimedb::reducer(client_disconnected)]
+pub fn client_disconnected(ctx: &ReducerContext) {
+    preview::clear_previews_of(ctx, ctx.sender);
+    presence::clear_presence_of(ctx, ctx.sender);
+    lock::release_locks_of(ctx, ctx.sender);
+}
+
+fn next_object_id(ctx: &ReducerContext) -> u64 {
+    match ctx.db.world_object_id_counter().key().find(&0) {
+        Some(counter) => counter.next_id,
+        // Databases created before the counter existed: continue after the highest id in use.
+        None => {
+            ctx.db
+                .world_object()
+                .iter()
+                .map(|row| row.id)
+                .max()
+                .unwrap_or(0)
+                + 1
+        }
+    }
+}
+
+fn set_next_object_id(ctx: &ReducerContext, next_id: u64) {
+    let counter = WorldObjectIdCounter { key: 0, next_id };
+    if ctx.db.world_object_id_counter().key().find(&0).is_some() {
+        ctx.db.world_object_id_counter().key().update(counter);
+    } else {
+        ctx.db.world_object_id_counter().insert(counter);
+    }
+}
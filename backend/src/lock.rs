@@ -0,0 +1,103 @@
+//! Lease-based locks on world objects.
+//!
+//! An editor locks the objects it is about to drag or edit in the details panel. While the
+//! lease runs, other identities can't lock them and their edit reducers are refused, so two
+//! editors can't silently overwrite each other. Leases expire unless renewed, and every lock
+//! an editor holds is released when it disconnects.
+
+use crate::{auth::ensure_editor, world_object};
+use spacetimedb::{Identity, ReducerContext, Table, TimeDuration, Timestamp};
+
+/// How long a lock holds without being renewed, in microseconds.
+const LOCK_LEASE_MICROS: i64 = 30_000_000;
+
+/// One locked object.
+#[spacetimedb::table(name = object_lock, public)]
+pub struct ObjectLock {
+    #[primary_key]
+    pub object_id: u64,
+
+    #[index(btree)]
+    pub owner: Identity,
+
+    pub acquired_at: Timestamp,
+
+    /// The lock is free for others to take from this point on.
+    pub expires_at: Timestamp,
+}
+
+/// Lock `ids` for the caller, or renew the caller's leases on them.
+///
+/// Fails without locking anything if another identity holds an unexpired lock on any of them.
+#[spacetimedb::reducer]
+pub fn acquire_locks(ctx: &ReducerContext, mut ids: Vec<u64>) -> Result<(), String> {
+    ensure_editor(ctx)?;
+
+    ids.sort_unstable();
+    ids.dedup();
+
+    let expires_at = ctx.timestamp + TimeDuration::from_micros(LOCK_LEASE_MICROS);
+    for id in ids {
+        if ctx.db.world_object().id().find(&id).is_none() {
+            return Err(format!("Unable to find object with ID: {}", id));
+        }
+        ensure_unlocked(ctx, id)?;
+
+        let lock = ObjectLock {
+            object_id: id,
+            owner: ctx.sender,
+            acquired_at: ctx.timestamp,
+            expires_at,
+        };
+        match ctx.db.object_lock().object_id().find(&id) {
+            Some(existing) if existing.owner == ctx.sender => {
+                ctx.db.object_lock().object_id().update(ObjectLock {
+                    acquired_at: existing.acquired_at,
+                    ..lock
+                });
+            }
+            // Expired or another identity's stale lease: take it over.
+            Some(_) => {
+                ctx.db.object_lock().object_id().update(lock);
+            }
+            None => {
+                ctx.db.object_lock().insert(lock);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Release the caller's locks on `ids`. Locks held by others are left alone.
+#[spacetimedb::reducer]
+pub fn release_locks(ctx: &ReducerContext, ids: Vec<u64>) -> Result<(), String> {
+    ensure_editor(ctx)?;
+
+    for id in ids {
+        if let Some(lock) = ctx.db.object_lock().object_id().find(&id)
+            && lock.owner == ctx.sender
+        {
+            ctx.db.object_lock().object_id().delete(&id);
+        }
+    }
+    Ok(())
+}
+
+/// Returns an error if another identity holds an unexpired lock on `id`.
+pub(crate) fn ensure_unlocked(ctx: &ReducerContext, id: u64) -> Result<(), String> {
+    match ctx.db.object_lock().object_id().find(&id) {
+        Some(lock) if lock.owner != ctx.sender && lock.expires_at > ctx.timestamp => {
+            Err(format!("Object with ID {} is locked by {}", id, lock.owner))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Drop the lock on a deleted object, whoever held it.
+pub(crate) fn drop_lock(ctx: &ReducerContext, id: u64) {
+    ctx.db.object_lock().object_id().delete(&id);
+}
+
+pub(crate) fn release_locks_of(ctx: &ReducerContext, owner: Identity) {
+    ctx.db.object_lock().owner().delete(&owner);
+}
@@ -0,0 +1,88 @@
+//! Where every connected editor is looking and what it has selected.
+//!
+//! Each editor publishes its camera and selection at a low rate so the others can draw it in
+//! the viewport. Rows only live as long as the connection: they are dropped on disconnect.
+
+use crate::{
+    auth::ensure_editor,
+    types::{Quat, Vec3},
+};
+use spacetimedb::{Identity, ReducerContext, Table, Timestamp};
+
+/// Longest display name `update_presence` accepts, in characters.
+const MAX_DISPLAY_NAME_CHARS: usize = 32;
+
+/// One connected editor.
+#[spacetimedb::table(name = editor_presence, public)]
+pub struct EditorPresence {
+    #[primary_key]
+    pub identity: Identity,
+
+    pub display_name: String,
+
+    /// The color other editors draw this one in, as `0xRRGGBB`.
+    pub color: u32,
+
+    /// World-space transform of the editor's camera.
+    pub camera_translation: Vec3,
+    pub camera_rotation: Quat,
+
+    /// Ids of the `world_object` rows the editor has selected.
+    pub selection: Vec<u64>,
+
+    pub updated_at: Timestamp,
+}
+
+/// Publish the caller's name, color, camera and selection.
+#[spacetimedb::reducer]
+pub fn update_presence(
+    ctx: &ReducerContext,
+    display_name: String,
+    color: u32,
+    camera_translation: Vec3,
+    camera_rotation: Quat,
+    selection: Vec<u64>,
+) -> Result<(), String> {
+    ensure_editor(ctx)?;
+
+    let display_name = display_name.trim().to_string();
+    if display_name.is_empty() {
+        return Err("Display name must not be empty".into());
+    }
+    if display_name.chars().count() > MAX_DISPLAY_NAME_CHARS {
+        return Err(format!(
+            "Display name must be at most {} characters",
+            MAX_DISPLAY_NAME_CHARS
+        ));
+    }
+    if color > 0xFF_FFFF {
+        return Err(format!("Invalid color: {:#x}", color));
+    }
+
+    let presence = EditorPresence {
+        identity: ctx.sender,
+        display_name,
+        color,
+        camera_translation,
+        camera_rotation,
+        selection,
+        updated_at: ctx.timestamp,
+    };
+
+    if ctx
+        .db
+        .editor_presence()
+        .identity()
+        .find(&ctx.sender)
+        .is_some()
+    {
+        ctx.db.editor_presence().identity().update(presence);
+    } else {
+        ctx.db.editor_presence().insert(presence);
+    }
+    Ok(())
+}
+
+pub(crate) fn clear_presence_of(ctx: &ReducerContext, identity: Identity) {
+    ctx.db.editor_presence().identity().delete(&identity);
+}
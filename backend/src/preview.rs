@@ -0,0 +1,116 @@
+//! Live transforms of objects an editor is dragging.
+//!
+//! Drags only call `move_object`/`rotate_object`/`scale_object` on release. While the drag is
+//! in progress the editor sends its current transforms here at a throttled rate, so other
+//! editors (and a running game) can show the motion live. Preview rows are never authoritative:
+//! the owner clears them after the final commit, and they are dropped when it disconnects.
+
+use crate::{
+    auth::ensure_editor,
+    lock::ensure_unlocked,
+    transform::{check_scale, check_translation, normalize_rotation},
+    types::{Quat, Vec3},
+    world_object,
+};
+use spacetimedb::{Identity, ReducerContext, SpacetimeType, Table, Timestamp};
+
+/// The transform an object is currently being dragged to.
+#[spacetimedb::table(name = transform_preview, public)]
+pub struct TransformPreview {
+    #[primary_key]
+    pub object_id: u64,
+
+    /// The editor dragging the object.
+    #[index(btree)]
+    pub owner: Identity,
+
+    pub translation: Vec3,
+    pub rotation: Quat,
+    pub scale: Vec3,
+
+    pub updated_at: Timestamp,
+}
+
+/// One object's in-progress transform, as sent by `preview_transforms`.
+#[derive(SpacetimeType, Debug, Clone, PartialEq)]
+pub struct PreviewTransform {
+    pub object_id: u64,
+    pub translation: Vec3,
+    pub rotation: Quat,
+    pub scale: Vec3,
+}
+
+/// Publish the caller's in-progress transforms for the objects it is dragging.
+///
+/// Objects another editor is already previewing are refused; the whole batch is rolled back.
+#[spacetimedb::reducer]
+pub fn preview_transforms(
+    ctx: &ReducerContext,
+    previews: Vec<PreviewTransform>,
+) -> Result<(), String> {
+    ensure_editor(ctx)?;
+
+    for preview in previews {
+        ensure_unlocked(ctx, preview.object_id)?;
+        if ctx
+            .db
+            .world_object()
+            .id()
+            .find(&preview.object_id)
+            .is_none()
+        {
+            return Err(format!(
+                "Unable to find object with ID: {}",
+                preview.object_id
+            ));
+        }
+
+        let row = TransformPreview {
+            object_id: preview.object_id,
+            owner: ctx.sender,
+            translation: check_translation(preview.translation)?,
+            rotation: normalize_rotation(preview.rotation)?,
+            scale: check_scale(preview.scale)?,
+            updated_at: ctx.timestamp,
+        };
+
+        match ctx
+            .db
+            .transform_preview()
+            .object_id()
+            .find(&preview.object_id)
+        {
+            Some(existing) if existing.owner != ctx.sender => {
+                return Err(format!(
+                    "Object with ID {} is being dragged by {}",
+                    preview.object_id, existing.owner
+                ));
+            }
+            Some(_) => {
+                ctx.db.transform_preview().object_id().update(row);
+            }
+            None => {
+                ctx.db.transform_preview().insert(row);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Drop every preview the caller published. Called once a drag is committed or cancelled.
+#[spacetimedb::reducer]
+pub fn clear_transform_previews(ctx: &ReducerContext) -> Result<(), String> {
+    ensure_editor(ctx)?;
+
+    clear_previews_of(ctx, ctx.sender);
+    Ok(())
+}
+
+/// Drop the preview of a replaced or deleted object, whoever published it.
+pub(crate) fn drop_preview(ctx: &ReducerContext, id: u64) {
+    ctx.db.transform_preview().object_id().delete(&id);
+}
+
+pub(crate) fn clear_previews_of(ctx: &ReducerContext, owner: Identity) {
+    ctx.db.transform_preview().owner().delete(&owner);
+}
@@ -1,11 +1,17 @@
 use crate::{
-    WorldObject,
+    WorldObject, allocate_object_id,
+    auth::ensure_editor,
+    edit_log::log_edit,
+    lock::{drop_lock, ensure_unlocked},
+    reserve_object_ids_up_to,
+    shape::CollisionShape,
+    transform::{check_object_transform, check_scale, check_translation, normalize_rotation},
     types::{AssetKind, Quat, Vec3},
     world_object,
 };
 use spacetimedb::{ReducerContext, Table};
 
-fn is_valid_relative_asset_path(path: &str) -> bool {
+pub(crate) fn is_valid_relative_asset_path(path: &str) -> bool {
     // We store asset paths relative to the asset root (Bevy AssetPlugin.file_path).
     // Examples:
     // - "FlightHelmet.gltf"
@@ -56,52 +62,175 @@ fn is_valid_relative_asset_path(path: &str) -> bool {
 }
 
 // --------------------------------------------------------------------------------
-// TODO: Use hard-coded JWT identity for editor to prevent these reducers from
-// being called by other clients, there isn't a way that I'm aware of to make these
-// public only to the editor client and have them ignored by the server otherwise.
+// Every reducer below edits the world and must only be callable by editors.
+// See `auth.rs` for how identities are granted editor rights.
 // --------------------------------------------------------------------------------
 
 #[spacetimedb::reducer]
-pub fn insert_object(ctx: &ReducerContext, mut object: WorldObject) {
+pub fn insert_object(ctx: &ReducerContext, mut object: WorldObject) -> Result<(), String> {
+    ensure_editor(ctx)?;
+
     if let AssetKind::Path(path) = object.asset {
         if !is_valid_relative_asset_path(&path) {
             log::warn!("insert_object rejected invalid asset path: {:?}", path);
-            return;
+            return Err(format!("Invalid asset path: {:?}", path));
         }
 
         // Normalize Windows separators to forward slashes so paths in the DB are consistent.
         object.asset = AssetKind::Path(path.replace('\\', "/"));
     }
 
+    if let AssetKind::PrimitiveShape(shape) = &object.asset {
+        shape.validate()?;
+    }
+    check_object_transform(&mut object)?;
+    object.collision_shape.validate()?;
+
+    // Ids are always assigned here; whatever the client sent is ignored.
+    object.id = allocate_object_id(ctx);
+    log_edit(ctx, "insert_object", object.id, None, Some(object.clone()));
+    ctx.db.world_object().insert(object);
+    Ok(())
+}
+
+/// Put a deleted object back with its original ID, e.g. when an editor undoes a delete.
+#[spacetimedb::reducer]
+pub fn restore_object(ctx: &ReducerContext, mut object: WorldObject) -> Result<(), String> {
+    ensure_editor(ctx)?;
+
+    if object.id == 0 {
+        return Err("Restored objects must have a non-zero ID".into());
+    }
+
+    if let AssetKind::Path(path) = object.asset {
+        if !is_valid_relative_asset_path(&path) {
+            log::warn!("restore_object rejected invalid asset path: {:?}", path);
+            return Err(format!("Invalid asset path: {:?}", path));
+        }
+        object.asset = AssetKind::Path(path.replace('\\', "/"));
+    }
+
+    if let AssetKind::PrimitiveShape(shape) = &object.asset {
+        shape.validate()?;
+    }
+    check_object_transform(&mut object)?;
+    object.collision_shape.validate()?;
+
+    if ctx.db.world_object().id().find(&object.id).is_some() {
+        return Err(format!("Object with ID {} already exists", object.id));
+    }
+
+    reserve_object_ids_up_to(ctx, object.id);
+    log_edit(ctx, "restore_object", object.id, None, Some(object.clone()));
     ctx.db.world_object().insert(object);
+    Ok(())
 }
 
 #[spacetimedb::reducer]
 pub fn move_object(ctx: &ReducerContext, id: u64, translation: Vec3) -> Result<(), String> {
+    ensure_editor(ctx)?;
+    ensure_unlocked(ctx, id)?;
+    let translation = check_translation(translation)?;
+
     let Some(mut object) = ctx.db.world_object().id().find(&id) else {
         return Err(format!("Unable to find object with ID: {}", id));
     };
+    let before = object.clone();
     object.translation = translation;
+    log_edit(ctx, "move_object", id, Some(before), Some(object.clone()));
     ctx.db.world_object().id().update(object);
     Ok(())
 }
 
 #[spacetimedb::reducer]
 pub fn rotate_object(ctx: &ReducerContext, id: u64, rotation: Quat) -> Result<(), String> {
+    ensure_editor(ctx)?;
+    ensure_unlocked(ctx, id)?;
+    let rotation = normalize_rotation(rotation)?;
+
     let Some(mut object) = ctx.db.world_object().id().find(&id) else {
         return Err(format!("Unable to find object with ID: {}", id));
     };
+    let before = object.clone();
     object.rotation = rotation;
+    log_edit(ctx, "rotate_object", id, Some(before), Some(object.clone()));
     ctx.db.world_object().id().update(object);
     Ok(())
 }
 
 #[spacetimedb::reducer]
 pub fn scale_object(ctx: &ReducerContext, id: u64, scale: Vec3) -> Result<(), String> {
+    ensure_editor(ctx)?;
+    ensure_unlocked(ctx, id)?;
+    let scale = check_scale(scale)?;
+
     let Some(mut object) = ctx.db.world_object().id().find(&id) else {
         return Err(format!("Unable to find object with ID: {}", id));
     };
+    let before = object.clone();
     object.scale = scale;
+    log_edit(ctx, "scale_object", id, Some(before), Some(object.clone()));
+    ctx.db.world_object().id().update(object);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn set_collision_shape(
+    ctx: &ReducerContext,
+    id: u64,
+    collision_shape: CollisionShape,
+) -> Result<(), String> {
+    ensure_editor(ctx)?;
+    ensure_unlocked(ctx, id)?;
+    collision_shape.validate()?;
+
+    let Some(mut object) = ctx.db.world_object().id().find(&id) else {
+        return Err(format!("Unable to find object with ID: {}", id));
+    };
+    let before = object.clone();
+    object.collision_shape = collision_shape;
+    log_edit(
+        ctx,
+        "set_collision_shape",
+        id,
+        Some(before),
+        Some(object.clone()),
+    );
     ctx.db.world_object().id().update(object);
     Ok(())
 }
+
+#[spacetimedb::reducer]
+pub fn delete_object(ctx: &ReducerContext, id: u64) -> Result<(), String> {
+    ensure_editor(ctx)?;
+    ensure_unlocked(ctx, id)?;
+
+    let Some(object) = ctx.db.world_object().id().find(&id) else {
+        return Err(format!("Unable to find object with ID: {}", id));
+    };
+    ctx.db.world_object().id().delete(&id);
+    drop_lock(ctx, id);
+    log_edit(ctx, "delete_object", id, Some(object), None);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn delete_objects(ctx: &ReducerContext, mut ids: Vec<u64>) -> Result<(), String> {
+    ensure_editor(ctx)?;
+
+    // Selecting the same object twice shouldn't fail the whole batch.
+    ids.sort_unstable();
+    ids.dedup();
+
+    // Returning an error rolls back the transaction, so a batch with any unknown id deletes nothing.
+    for id in ids {
+        ensure_unlocked(ctx, id)?;
+        let Some(object) = ctx.db.world_object().id().find(&id) else {
+            return Err(format!("Unable to find object with ID: {}", id));
+        };
+        ctx.db.world_object().id().delete(&id);
+        drop_lock(ctx, id);
+        log_edit(ctx, "delete_objects", id, Some(object), None);
+    }
+    Ok(())
+}
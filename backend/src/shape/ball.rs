@@ -3,3 +3,40 @@
 pub struct Ball {
     pub radius: f32,
 }
+
+impl Ball {
+    pub fn validate(&self) -> Result<(), String> {
+        if !(self.radius.is_finite() && self.radius > 0.0) {
+            return Err(format!("Ball radius must be positive, got {}", self.radius));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_positive_radius() {
+        assert!(Ball { radius: 0.5 }.validate().is_ok());
+    }
+
+    #[test]
+    fn rejects_non_positive_radius() {
+        assert!(Ball { radius: 0.0 }.validate().is_err());
+        assert!(Ball { radius: -1.0 }.validate().is_err());
+    }
+
+    #[test]
+    fn rejects_non_finite_radius() {
+        assert!(Ball { radius: f32::NAN }.validate().is_err());
+        assert!(
+            Ball {
+                radius: f32::INFINITY
+            }
+            .validate()
+            .is_err()
+        );
+    }
+}
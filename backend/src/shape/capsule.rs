@@ -6,3 +6,51 @@ pub struct Capsule {
     pub segment: Segment,
     pub radius: f32,
 }
+
+impl Capsule {
+    pub fn validate(&self) -> Result<(), String> {
+        if !(self.radius.is_finite() && self.radius > 0.0) {
+            return Err(format!(
+                "Capsule radius must be positive, got {}",
+                self.radius
+            ));
+        }
+        self.segment
+            .validate()
+            .map_err(|err| format!("Capsule segment is invalid: {}", err))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Vec3;
+
+    fn capsule(radius: f32, b: Vec3) -> Capsule {
+        Capsule {
+            segment: Segment { a: Vec3::ZERO, b },
+            radius,
+        }
+    }
+
+    #[test]
+    fn accepts_valid_capsule() {
+        assert!(capsule(0.25, Vec3::ONE).validate().is_ok());
+    }
+
+    #[test]
+    fn rejects_non_positive_radius() {
+        assert!(capsule(0.0, Vec3::ONE).validate().is_err());
+        assert!(capsule(-0.25, Vec3::ONE).validate().is_err());
+        assert!(capsule(f32::NAN, Vec3::ONE).validate().is_err());
+    }
+
+    #[test]
+    fn rejects_non_finite_segment() {
+        let b = Vec3 {
+            y: f32::INFINITY,
+            ..Vec3::ONE
+        };
+        assert!(capsule(0.25, b).validate().is_err());
+    }
+}
@@ -17,3 +17,55 @@ impl Default for CollisionShape {
         Self::None
     }
 }
+
+impl CollisionShape {
+    /// Returns an error describing the first problem that would break physics on this shape.
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            CollisionShape::None => Ok(()),
+            CollisionShape::Cuboid(cuboid) => cuboid.validate(),
+            CollisionShape::Ball(ball) => ball.validate(),
+            CollisionShape::Capsule(capsule) => capsule.validate(),
+            CollisionShape::Heightfield(heightfield) => heightfield.validate(),
+            CollisionShape::ConvexHull(convex_hull) => convex_hull.validate(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_none() {
+        assert!(CollisionShape::None.validate().is_ok());
+    }
+
+    #[test]
+    fn validates_the_wrapped_shape() {
+        assert!(
+            CollisionShape::Ball(Ball { radius: 1.0 })
+                .validate()
+                .is_ok()
+        );
+
+        let err = CollisionShape::Ball(Ball { radius: -1.0 })
+            .validate()
+            .unwrap_err();
+        assert!(err.contains("Ball radius"), "{err}");
+
+        let heightfield = Heightfield {
+            width: 3,
+            height: 3,
+            heights: vec![0.0; 8],
+            ..Default::default()
+        };
+        assert!(CollisionShape::Heightfield(heightfield).validate().is_err());
+
+        assert!(
+            CollisionShape::ConvexHull(ConvexHull::default())
+                .validate()
+                .is_err()
+        );
+    }
+}
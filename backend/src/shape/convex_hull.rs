@@ -1,6 +1,10 @@
 use super::triangle::Triangle;
 use crate::types::Vec3;
 
+/// Fewest points and triangles that enclose a volume: a tetrahedron.
+const MIN_POINTS: usize = 4;
+const MIN_TRIANGLES: usize = 4;
+
 #[derive(spacetimedb::SpacetimeType, Clone, Default, Debug, PartialEq)]
 pub struct ConvexHull {
     /// Point cloud
@@ -8,3 +12,101 @@ pub struct ConvexHull {
     /// Triangles that form the hull
     pub indices: Vec<Triangle>,
 }
+
+impl ConvexHull {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.points.len() < MIN_POINTS {
+            return Err(format!(
+                "ConvexHull needs at least {} points, got {}",
+                MIN_POINTS,
+                self.points.len()
+            ));
+        }
+        if self.indices.len() < MIN_TRIANGLES {
+            return Err(format!(
+                "ConvexHull needs at least {} triangles, got {}",
+                MIN_TRIANGLES,
+                self.indices.len()
+            ));
+        }
+
+        if let Some(index) = self.points.iter().position(|p| !p.is_finite()) {
+            return Err(format!(
+                "ConvexHull point {} is not finite: {:?}",
+                index, self.points[index]
+            ));
+        }
+
+        for (index, triangle) in self.indices.iter().enumerate() {
+            triangle
+                .validate(self.points.len())
+                .map_err(|err| format!("ConvexHull triangle {} is invalid: {}", index, err))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(x: f32, y: f32, z: f32) -> Vec3 {
+        Vec3 { x, y, z }
+    }
+
+    fn triangle(v1: u32, v2: u32, v3: u32) -> Triangle {
+        Triangle { v1, v2, v3 }
+    }
+
+    fn tetrahedron() -> ConvexHull {
+        ConvexHull {
+            points: vec![
+                point(0.0, 0.0, 0.0),
+                point(1.0, 0.0, 0.0),
+                point(0.0, 1.0, 0.0),
+                point(0.0, 0.0, 1.0),
+            ],
+            indices: vec![
+                triangle(0, 2, 1),
+                triangle(0, 1, 3),
+                triangle(0, 3, 2),
+                triangle(1, 2, 3),
+            ],
+        }
+    }
+
+    #[test]
+    fn accepts_tetrahedron() {
+        assert!(tetrahedron().validate().is_ok());
+    }
+
+    #[test]
+    fn rejects_out_of_range_index() {
+        let mut hull = tetrahedron();
+        hull.indices[3] = triangle(1, 2, 4);
+        let err = hull.validate().unwrap_err();
+        assert!(err.contains("triangle 3"), "{err}");
+        assert!(err.contains("out of range for 4 points"), "{err}");
+    }
+
+    #[test]
+    fn rejects_too_few_points_or_triangles() {
+        let mut hull = tetrahedron();
+        hull.points.pop();
+        assert!(hull.validate().is_err());
+
+        let mut hull = tetrahedron();
+        hull.indices.pop();
+        assert!(hull.validate().is_err());
+
+        assert!(ConvexHull::default().validate().is_err());
+    }
+
+    #[test]
+    fn rejects_non_finite_points() {
+        let mut hull = tetrahedron();
+        hull.points[1] = point(f32::NAN, 0.0, 0.0);
+        let err = hull.validate().unwrap_err();
+        assert!(err.contains("point 1"), "{err}");
+    }
+}
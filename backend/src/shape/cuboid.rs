@@ -5,3 +5,42 @@ use crate::types::Vec3;
 pub struct Cuboid {
     pub half_extents: Vec3,
 }
+
+impl Cuboid {
+    pub fn validate(&self) -> Result<(), String> {
+        let h = self.half_extents;
+        if !(h.is_finite() && h.x > 0.0 && h.y > 0.0 && h.z > 0.0) {
+            return Err(format!("Cuboid half extents must be positive, got {:?}", h));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cuboid(x: f32, y: f32, z: f32) -> Cuboid {
+        Cuboid {
+            half_extents: Vec3 { x, y, z },
+        }
+    }
+
+    #[test]
+    fn accepts_positive_half_extents() {
+        assert!(cuboid(0.5, 1.0, 2.0).validate().is_ok());
+    }
+
+    #[test]
+    fn rejects_non_positive_half_extents() {
+        assert!(cuboid(0.0, 1.0, 1.0).validate().is_err());
+        assert!(cuboid(1.0, -1.0, 1.0).validate().is_err());
+        assert!(cuboid(1.0, 1.0, 0.0).validate().is_err());
+    }
+
+    #[test]
+    fn rejects_non_finite_half_extents() {
+        assert!(cuboid(f32::NAN, 1.0, 1.0).validate().is_err());
+        assert!(cuboid(1.0, f32::INFINITY, 1.0).validate().is_err());
+    }
+}
@@ -8,3 +8,93 @@ pub struct Heightfield {
     pub heights: Vec<f32>,
     pub scale: Vec3,
 }
+
+impl Heightfield {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.width < 2 || self.height < 2 {
+            return Err(format!(
+                "Heightfield must have at least 2 × 2 samples, got {} × {}",
+                self.width, self.height
+            ));
+        }
+
+        let expected = (self.width as usize).checked_mul(self.height as usize);
+        if expected != Some(self.heights.len()) {
+            return Err(format!(
+                "Heightfield of {} × {} samples needs {} heights, got {}",
+                self.width,
+                self.height,
+                u64::from(self.width) * u64::from(self.height),
+                self.heights.len()
+            ));
+        }
+
+        if let Some(index) = self.heights.iter().position(|h| !h.is_finite()) {
+            return Err(format!(
+                "Heightfield height {} is not finite: {}",
+                index, self.heights[index]
+            ));
+        }
+
+        let s = self.scale;
+        if !(s.is_finite() && s.x > 0.0 && s.y > 0.0 && s.z > 0.0) {
+            return Err(format!("Heightfield scale must be positive, got {:?}", s));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn heightfield(width: u32, height: u32, heights: Vec<f32>) -> Heightfield {
+        Heightfield {
+            width,
+            height,
+            heights,
+            scale: Vec3::ONE,
+        }
+    }
+
+    #[test]
+    fn accepts_matching_heights() {
+        assert!(heightfield(2, 3, vec![0.0; 6]).validate().is_ok());
+    }
+
+    #[test]
+    fn rejects_mismatched_heights() {
+        let err = heightfield(2, 3, vec![0.0; 5]).validate().unwrap_err();
+        assert!(err.contains("needs 6 heights, got 5"), "{err}");
+        assert!(heightfield(2, 3, vec![0.0; 7]).validate().is_err());
+    }
+
+    #[test]
+    fn rejects_too_few_samples() {
+        assert!(heightfield(1, 4, vec![0.0; 4]).validate().is_err());
+        assert!(heightfield(0, 0, Vec::new()).validate().is_err());
+    }
+
+    #[test]
+    fn rejects_non_finite_heights() {
+        let mut heights = vec![0.0; 4];
+        heights[2] = f32::NAN;
+        let err = heightfield(2, 2, heights).validate().unwrap_err();
+        assert!(err.contains("height 2"), "{err}");
+    }
+
+    #[test]
+    fn rejects_non_positive_scale() {
+        let mut field = heightfield(2, 2, vec![0.0; 4]);
+        field.scale = Vec3 {
+            y: 0.0,
+            ..Vec3::ONE
+        };
+        assert!(field.validate().is_err());
+        field.scale = Vec3 {
+            x: f32::NAN,
+            ..Vec3::ONE
+        };
+        assert!(field.validate().is_err());
+    }
+}
@@ -6,3 +6,67 @@ pub enum PrimitiveShape {
     Ball(Ball),
     Capsule(Capsule),
 }
+
+impl PrimitiveShape {
+    /// Returns an error describing the first problem that would keep this shape from rendering.
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            PrimitiveShape::Cuboid(cuboid) => cuboid.validate(),
+            PrimitiveShape::Ball(ball) => ball.validate(),
+            PrimitiveShape::Capsule(capsule) => capsule.validate(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{shape::segment::Segment, types::Vec3};
+
+    #[test]
+    fn validates_the_wrapped_shape() {
+        assert!(
+            PrimitiveShape::Ball(Ball { radius: 0.5 })
+                .validate()
+                .is_ok()
+        );
+        assert!(
+            PrimitiveShape::Ball(Ball { radius: 0.0 })
+                .validate()
+                .is_err()
+        );
+        assert!(
+            PrimitiveShape::Ball(Ball { radius: f32::NAN })
+                .validate()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn rejects_bad_cuboid_extents() {
+        let cuboid = |x, y, z| {
+            PrimitiveShape::Cuboid(Cuboid {
+                half_extents: Vec3 { x, y, z },
+            })
+        };
+        assert!(cuboid(1.0, 2.0, 3.0).validate().is_ok());
+        assert!(cuboid(1.0, 0.0, 3.0).validate().is_err());
+        assert!(cuboid(-1.0, 2.0, 3.0).validate().is_err());
+        assert!(cuboid(1.0, 2.0, f32::INFINITY).validate().is_err());
+    }
+
+    #[test]
+    fn rejects_bad_capsule_radius() {
+        let capsule = |radius| {
+            PrimitiveShape::Capsule(Capsule {
+                segment: Segment {
+                    a: Vec3::ZERO,
+                    b: Vec3::ONE,
+                },
+                radius,
+            })
+        };
+        assert!(capsule(0.5).validate().is_ok());
+        assert!(capsule(-0.5).validate().is_err());
+    }
+}
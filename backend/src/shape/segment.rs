@@ -7,3 +7,51 @@ pub struct Segment {
     pub a: Vec3,
     pub b: Vec3,
 }
+
+impl Segment {
+    pub fn validate(&self) -> Result<(), String> {
+        if !(self.a.is_finite() && self.b.is_finite()) {
+            return Err(format!(
+                "Segment endpoints must be finite, got {:?} and {:?}",
+                self.a, self.b
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_finite_endpoints() {
+        let segment = Segment {
+            a: Vec3::ZERO,
+            b: Vec3::ONE,
+        };
+        assert!(segment.validate().is_ok());
+    }
+
+    #[test]
+    fn accepts_coincident_endpoints() {
+        // A zero-length capsule is a ball; physics engines handle it fine.
+        let segment = Segment {
+            a: Vec3::ONE,
+            b: Vec3::ONE,
+        };
+        assert!(segment.validate().is_ok());
+    }
+
+    #[test]
+    fn rejects_non_finite_endpoints() {
+        let segment = Segment {
+            a: Vec3::ZERO,
+            b: Vec3 {
+                x: f32::NAN,
+                ..Vec3::ONE
+            },
+        };
+        assert!(segment.validate().is_err());
+    }
+}
@@ -4,3 +4,51 @@ pub struct Triangle {
     pub v2: u32,
     pub v3: u32,
 }
+
+impl Triangle {
+    /// Checks the triangle against a list of `point_count` vertices.
+    pub fn validate(&self, point_count: usize) -> Result<(), String> {
+        for index in [self.v1, self.v2, self.v3] {
+            if index as usize >= point_count {
+                return Err(format!(
+                    "Triangle index {} is out of range for {} points",
+                    index, point_count
+                ));
+            }
+        }
+        if self.v1 == self.v2 || self.v2 == self.v3 || self.v3 == self.v1 {
+            return Err(format!(
+                "Triangle ({}, {}, {}) repeats a vertex",
+                self.v1, self.v2, self.v3
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn triangle(v1: u32, v2: u32, v3: u32) -> Triangle {
+        Triangle { v1, v2, v3 }
+    }
+
+    #[test]
+    fn accepts_indices_in_range() {
+        assert!(triangle(0, 1, 2).validate(3).is_ok());
+    }
+
+    #[test]
+    fn rejects_out_of_range_indices() {
+        assert!(triangle(0, 1, 3).validate(3).is_err());
+        assert!(triangle(u32::MAX, 0, 1).validate(3).is_err());
+        assert!(triangle(0, 1, 2).validate(0).is_err());
+    }
+
+    #[test]
+    fn rejects_repeated_vertices() {
+        assert!(triangle(0, 0, 1).validate(3).is_err());
+        assert!(triangle(2, 1, 2).validate(3).is_err());
+    }
+}
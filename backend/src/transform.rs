@@ -0,0 +1,162 @@
+//! Checks on the transforms stored in `world_object`.
+//!
+//! Every client builds its scene from these rows, so a NaN, a zero-length quaternion or a zero
+//! scale would break all of them at once. Reducers run incoming values through here before
+//! storing them.
+
+use crate::{
+    WorldObject,
+    types::{Quat, Vec3},
+};
+
+/// Quaternions shorter than this have no meaningful direction to normalize to.
+const MIN_QUAT_LENGTH: f32 = 1e-4;
+
+/// Smallest magnitude a scale component may have. Negative scales (mirroring) are fine.
+const MIN_SCALE: f32 = 1e-4;
+
+pub(crate) fn check_translation(translation: Vec3) -> Result<Vec3, String> {
+    if !translation.is_finite() {
+        return Err(format!("Translation must be finite, got {:?}", translation));
+    }
+    Ok(translation)
+}
+
+/// The unit quaternion `rotation` points towards.
+pub(crate) fn normalize_rotation(rotation: Quat) -> Result<Quat, String> {
+    let Quat { x, y, z, w } = rotation;
+    if !(x.is_finite() && y.is_finite() && z.is_finite() && w.is_finite()) {
+        return Err(format!("Rotation must be finite, got {:?}", rotation));
+    }
+
+    // In f64 so components near f32::MAX don't overflow the squared length.
+    let length = [x, y, z, w]
+        .iter()
+        .map(|c| f64::from(*c).powi(2))
+        .sum::<f64>()
+        .sqrt();
+    if length < f64::from(MIN_QUAT_LENGTH) {
+        return Err(format!(
+            "Rotation must not be a zero-length quaternion, got {:?}",
+            rotation
+        ));
+    }
+
+    let normalize = |c: f32| (f64::from(c) / length) as f32;
+    Ok(Quat {
+        x: normalize(x),
+        y: normalize(y),
+        z: normalize(z),
+        w: normalize(w),
+    })
+}
+
+pub(crate) fn check_scale(scale: Vec3) -> Result<Vec3, String> {
+    if !scale.is_finite() {
+        return Err(format!("Scale must be finite, got {:?}", scale));
+    }
+    if [scale.x, scale.y, scale.z]
+        .iter()
+        .any(|component| component.abs() < MIN_SCALE)
+    {
+        return Err(format!(
+            "Scale components must be at least {} in magnitude, got {:?}",
+            MIN_SCALE, scale
+        ));
+    }
+    Ok(scale)
+}
+
+/// Check all of `object`'s transform, normalizing its rotation.
+pub(crate) fn check_object_transform(object: &mut WorldObject) -> Result<(), String> {
+    object.translation = check_translation(object.translation)?;
+    object.rotation = normalize_rotation(object.rotation)?;
+    object.scale = check_scale(object.scale)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vec3(x: f32, y: f32, z: f32) -> Vec3 {
+        Vec3 { x, y, z }
+    }
+
+    fn quat(x: f32, y: f32, z: f32, w: f32) -> Quat {
+        Quat { x, y, z, w }
+    }
+
+    #[test]
+    fn translation_accepts_finite_values() {
+        let translation = vec3(-1.0e6, 0.0, 3.5);
+        assert_eq!(check_translation(translation), Ok(translation));
+    }
+
+    #[test]
+    fn translation_rejects_nan_and_infinity() {
+        assert!(check_translation(vec3(f32::NAN, 0.0, 0.0)).is_err());
+        assert!(check_translation(vec3(0.0, f32::INFINITY, 0.0)).is_err());
+        assert!(check_translation(vec3(0.0, 0.0, f32::NEG_INFINITY)).is_err());
+    }
+
+    #[test]
+    fn rotation_keeps_unit_quaternions() {
+        let identity = quat(0.0, 0.0, 0.0, 1.0);
+        assert_eq!(normalize_rotation(identity), Ok(identity));
+    }
+
+    #[test]
+    fn rotation_normalizes_scaled_quaternions() {
+        let rotation = normalize_rotation(quat(0.0, 2.0, 0.0, 2.0)).unwrap();
+        let half_sqrt2 = std::f32::consts::FRAC_1_SQRT_2;
+        assert!((rotation.y - half_sqrt2).abs() < 1e-6);
+        assert!((rotation.w - half_sqrt2).abs() < 1e-6);
+        assert_eq!((rotation.x, rotation.z), (0.0, 0.0));
+    }
+
+    #[test]
+    fn rotation_normalizes_short_quaternions_above_the_threshold() {
+        let rotation = normalize_rotation(quat(0.0, 0.0, 0.0, 1e-3)).unwrap();
+        assert!((rotation.w - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn rotation_rejects_zero_and_near_zero_length() {
+        assert!(normalize_rotation(quat(0.0, 0.0, 0.0, 0.0)).is_err());
+        assert!(normalize_rotation(quat(1e-6, 0.0, 0.0, 1e-6)).is_err());
+    }
+
+    #[test]
+    fn rotation_rejects_non_finite_components() {
+        assert!(normalize_rotation(quat(f32::NAN, 0.0, 0.0, 1.0)).is_err());
+        assert!(normalize_rotation(quat(0.0, 0.0, 0.0, f32::INFINITY)).is_err());
+    }
+
+    #[test]
+    fn rotation_normalizes_huge_components_without_overflow() {
+        let rotation = normalize_rotation(quat(f32::MAX, 0.0, 0.0, f32::MAX)).unwrap();
+        let half_sqrt2 = std::f32::consts::FRAC_1_SQRT_2;
+        assert!((rotation.x - half_sqrt2).abs() < 1e-6);
+        assert!((rotation.w - half_sqrt2).abs() < 1e-6);
+    }
+
+    #[test]
+    fn scale_accepts_positive_and_mirrored_values() {
+        assert!(check_scale(Vec3::ONE).is_ok());
+        assert!(check_scale(vec3(-1.0, 2.0, MIN_SCALE)).is_ok());
+    }
+
+    #[test]
+    fn scale_rejects_zero_and_tiny_components() {
+        assert!(check_scale(Vec3::ZERO).is_err());
+        assert!(check_scale(vec3(1.0, 0.0, 1.0)).is_err());
+        assert!(check_scale(vec3(1.0, 1.0, -1e-6)).is_err());
+    }
+
+    #[test]
+    fn scale_rejects_non_finite_components() {
+        assert!(check_scale(vec3(f32::NAN, 1.0, 1.0)).is_err());
+        assert!(check_scale(vec3(1.0, f32::INFINITY, 1.0)).is_err());
+    }
+}
@@ -17,6 +17,10 @@ impl Vec3 {
         y: 1.0,
         z: 1.0,
     };
+
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite()
+    }
 }
 
 #[derive(spacetimedb::SpacetimeType, Debug, Default, Clone, Copy, PartialEq)]
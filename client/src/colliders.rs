@@ -0,0 +1,253 @@
+//! Collider wireframes: each object's `collision_shape` drawn as lines over the scene.
+//!
+//! The lines are built in the object's local space whenever its row is inserted or its shape
+//! changes (see `world_object`), and drawn through the entity's `GlobalTransform`, so they
+//! follow drags, previews and row updates like the rendered asset does. Toggled from the View
+//! menu or with C. A generated convex hull waiting to be applied is always drawn, in its own
+//! color.
+
+use std::f32::consts::{PI, TAU};
+
+use bevy::{platform::collections::HashSet, prelude::*, transform::TransformSystems};
+use bevy_egui::EguiContexts;
+
+use crate::{
+    convex_hull::{HullPreview, PendingHull},
+    flycam::FlyCamActive,
+    module_bindings::{Capsule, CollisionShape, ConvexHull, Heightfield},
+    world_object::WorldObjectIndex,
+};
+
+const WIREFRAME_COLOR: Color = Color::srgb(0.3, 1.0, 0.5);
+const HULL_PREVIEW_COLOR: Color = Color::srgb(1.0, 0.4, 0.9);
+
+/// Line segments per full circle of a ball or capsule.
+const CIRCLE_SEGMENTS: usize = 32;
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_gizmo_group::<ColliderGizmos>();
+    app.init_resource::<ColliderWireframesEnabled>();
+    app.add_systems(Startup, configure_gizmos);
+    app.add_systems(Update, toggle_on_key);
+    app.add_systems(
+        PostUpdate,
+        (
+            draw_wireframes.run_if(|enabled: Res<ColliderWireframesEnabled>| enabled.0),
+            draw_hull_preview,
+        )
+            .after(TransformSystems::Propagate),
+    );
+}
+
+#[derive(Resource, Copy, Clone, Debug, Default)]
+pub struct ColliderWireframesEnabled(pub bool);
+
+/// Gizmo config group for collider wireframes, drawn thinner than the transform gizmo.
+#[derive(Default, Reflect, GizmoConfigGroup)]
+struct ColliderGizmos;
+
+/// An object's collision shape as line segments in its local space.
+#[derive(Component, Debug, Default)]
+pub struct ColliderWireframe {
+    lines: Vec<[Vec3; 2]>,
+}
+
+impl ColliderWireframe {
+    pub fn new(shape: &CollisionShape) -> Self {
+        let mut lines = Vec::new();
+        match shape {
+            CollisionShape::None => {}
+            CollisionShape::Cuboid(cuboid) => {
+                cuboid_lines(&mut lines, cuboid.half_extents.clone().into())
+            }
+            CollisionShape::Ball(ball) => ball_lines(&mut lines, ball.radius),
+            CollisionShape::Capsule(capsule) => capsule_lines(&mut lines, capsule),
+            CollisionShape::Heightfield(heightfield) => heightfield_lines(&mut lines, heightfield),
+            CollisionShape::ConvexHull(hull) => convex_hull_lines(&mut lines, hull),
+        }
+        Self { lines }
+    }
+}
+
+fn configure_gizmos(mut config_store: ResMut<GizmoConfigStore>) {
+    let (config, _) = config_store.config_mut::<ColliderGizmos>();
+    config.line.width = 1.5;
+}
+
+/// Toggle the wireframes with C, gated like the transform tool hotkeys.
+fn toggle_on_key(
+    keys: Res<ButtonInput<KeyCode>>,
+    flycam_active: Res<FlyCamActive>,
+    mut enabled: ResMut<ColliderWireframesEnabled>,
+    mut contexts: EguiContexts,
+) {
+    if !keys.just_pressed(KeyCode::KeyC) || flycam_active.0 {
+        return;
+    }
+
+    if let Ok(ctx) = contexts.ctx_mut()
+        && (ctx.wants_keyboard_input() || ctx.wants_pointer_input())
+    {
+        return;
+    }
+
+    enabled.0 = !enabled.0;
+}
+
+fn draw_wireframes(
+    mut gizmos: Gizmos<ColliderGizmos>,
+    wireframes: Query<(&ColliderWireframe, &GlobalTransform)>,
+) {
+    for (wireframe, transform) in &wireframes {
+        draw_lines(&mut gizmos, wireframe, transform, WIREFRAME_COLOR);
+    }
+}
+
+fn draw_hull_preview(
+    mut gizmos: Gizmos<ColliderGizmos>,
+    preview: Res<HullPreview>,
+    index: Res<WorldObjectIndex>,
+    transforms: Query<&GlobalTransform>,
+) {
+    let Some(PendingHull {
+        id,
+        result: Ok(hull),
+    }) = &preview.0
+    else {
+        return;
+    };
+    let Some(transform) = index
+        .get(*id)
+        .and_then(|entity| transforms.get(entity).ok())
+    else {
+        return;
+    };
+    draw_lines(&mut gizmos, &hull.wireframe, transform, HULL_PREVIEW_COLOR);
+}
+
+fn draw_lines(
+    gizmos: &mut Gizmos<ColliderGizmos>,
+    wireframe: &ColliderWireframe,
+    transform: &GlobalTransform,
+    color: Color,
+) {
+    for [a, b] in &wireframe.lines {
+        gizmos.line(
+            transform.transform_point(*a),
+            transform.transform_point(*b),
+            color,
+        );
+    }
+}
+
+fn cuboid_lines(lines: &mut Vec<[Vec3; 2]>, half_extents: Vec3) {
+    let corner = |i: usize| {
+        half_extents
+            * Vec3::new(
+                if i & 1 == 0 { -1.0 } else { 1.0 },
+                if i & 2 == 0 { -1.0 } else { 1.0 },
+                if i & 4 == 0 { -1.0 } else { 1.0 },
+            )
+    };
+    // Corners differing in exactly one bit share an edge.
+    for i in 0..8 {
+        for bit in [1, 2, 4] {
+            if i & bit == 0 {
+                lines.push([corner(i), corner(i | bit)]);
+            }
+        }
+    }
+}
+
+/// Three great circles, one per axis.
+fn ball_lines(lines: &mut Vec<[Vec3; 2]>, radius: f32) {
+    arc_lines(lines, Vec3::ZERO, Vec3::X, Vec3::Y, radius, TAU);
+    arc_lines(lines, Vec3::ZERO, Vec3::Y, Vec3::Z, radius, TAU);
+    arc_lines(lines, Vec3::ZERO, Vec3::Z, Vec3::X, radius, TAU);
+}
+
+/// A circle around each endpoint, the half circles capping them and four lines along the side.
+fn capsule_lines(lines: &mut Vec<[Vec3; 2]>, capsule: &Capsule) {
+    let a: Vec3 = capsule.segment.a.clone().into();
+    let b: Vec3 = capsule.segment.b.clone().into();
+    let radius = capsule.radius;
+
+    let axis = (b - a).try_normalize().unwrap_or(Vec3::Y);
+    let (u, v) = axis.any_orthonormal_pair();
+
+    arc_lines(lines, a, u, v, radius, TAU);
+    arc_lines(lines, b, u, v, radius, TAU);
+    for side in [u, v] {
+        arc_lines(lines, b, side, axis, radius, PI);
+        arc_lines(lines, a, -side, -axis, radius, PI);
+    }
+    for side in [u, -u, v, -v] {
+        lines.push([a + side * radius, b + side * radius]);
+    }
+}
+
+/// An arc of `angle` radians starting at `center + from * radius` and turning towards `to`.
+fn arc_lines(
+    lines: &mut Vec<[Vec3; 2]>,
+    center: Vec3,
+    from: Vec3,
+    to: Vec3,
+    radius: f32,
+    angle: f32,
+) {
+    let segments = ((CIRCLE_SEGMENTS as f32 * angle / TAU).ceil() as usize).max(1);
+    let point = |i: usize| {
+        let t = angle * i as f32 / segments as f32;
+        center + (from * t.cos() + to * t.sin()) * radius
+    };
+    for i in 0..segments {
+        lines.push([point(i), point(i + 1)]);
+    }
+}
+
+/// The sample grid: `width` samples along X, `height` rows along Z, centered on the origin and
+/// spanning `scale` on X and Z. Heights are row-major and multiplied by `scale.y`.
+fn heightfield_lines(lines: &mut Vec<[Vec3; 2]>, heightfield: &Heightfield) {
+    let (width, height) = (heightfield.width as usize, heightfield.height as usize);
+    if width < 2 || height < 2 || heightfield.heights.len() != width * height {
+        return;
+    }
+
+    let scale: Vec3 = heightfield.scale.clone().into();
+    let point = |x: usize, z: usize| {
+        Vec3::new(
+            (x as f32 / (width - 1) as f32 - 0.5) * scale.x,
+            heightfield.heights[z * width + x] * scale.y,
+            (z as f32 / (height - 1) as f32 - 0.5) * scale.z,
+        )
+    };
+    for z in 0..height {
+        for x in 0..width {
+            if x + 1 < width {
+                lines.push([point(x, z), point(x + 1, z)]);
+            }
+            if z + 1 < height {
+                lines.push([point(x, z), point(x, z + 1)]);
+            }
+        }
+    }
+}
+
+/// Every triangle edge once. Triangles with out-of-range indices are skipped.
+fn convex_hull_lines(lines: &mut Vec<[Vec3; 2]>, hull: &ConvexHull) {
+    let points: Vec<Vec3> = hull.points.iter().map(|p| p.clone().into()).collect();
+
+    let mut edges = HashSet::new();
+    for triangle in &hull.indices {
+        let corners = [triangle.v_1, triangle.v_2, triangle.v_3].map(|i| i as usize);
+        if corners.iter().any(|i| *i >= points.len()) {
+            continue;
+        }
+        for (i, j) in [(0, 1), (1, 2), (2, 0)] {
+            let (a, b) = (corners[i], corners[j]);
+            if edges.insert((a.min(b), a.max(b))) {
+                lines.push([points[a], points[b]]);
+            }
+        }
+    }
+}
@@ -9,6 +9,8 @@ use bevy::prelude::*;
 /// - `EDITOR_SPACETIME_URL`  (default: `ws://127.0.0.1:3000`)
 /// - `EDITOR_SPACETIME_NAME` (default: `default`)
 /// - `EDITOR_ASSET_PATH`     (optional; when unset Bevy defaults to `assets`)
+/// - `EDITOR_PROJECT_ROOT`   (optional; when unset the parent of the asset root is used)
+/// - `EDITOR_DISPLAY_NAME`   (optional; when unset other editors see an abbreviated identity)
 #[derive(Resource, Clone, Debug)]
 pub struct ClientRuntimeConfig {
     /// SpacetimeDB websocket URL, e.g. "ws://127.0.0.1:3000"
@@ -18,6 +20,11 @@ pub struct ClientRuntimeConfig {
     /// Optional asset root override. If set, Bevy will load assets relative to this directory.
     /// Useful when the editor needs to pull assets from a shared location.
     pub asset_path: Option<String>,
+    /// Optional project root override. Editor-local files (e.g. the world snapshot backup)
+    /// live under `<project_root>/.editor/`.
+    pub project_root: Option<String>,
+    /// Optional name other editors see next to this editor's camera.
+    pub display_name: Option<String>,
 }
 
 impl ClientRuntimeConfig {
@@ -32,10 +39,21 @@ impl ClientRuntimeConfig {
             .ok()
             .filter(|s| !s.is_empty());
 
+        let project_root = std::env::var("EDITOR_PROJECT_ROOT")
+            .ok()
+            .filter(|s| !s.is_empty());
+
+        let display_name = std::env::var("EDITOR_DISPLAY_NAME")
+            .ok()
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty());
+
         Self {
             spacetime_url,
             spacetime_name,
             asset_path,
+            project_root,
+            display_name,
         }
     }
 
@@ -57,6 +75,33 @@ impl ClientRuntimeConfig {
         self.asset_root_resolved_or_default()
     }
 
+    /// Returns the directory for editor-local files: `<project_root>/.editor`.
+    ///
+    /// A relative `EDITOR_PROJECT_ROOT` is resolved against the client crate directory, like
+    /// `EDITOR_ASSET_PATH`. When unset, the project root is the directory containing the
+    /// asset root (a Bevy game keeps `assets/` next to its `Cargo.toml`).
+    pub fn editor_dir(&self) -> std::path::PathBuf {
+        let project_root = match self.project_root.as_deref() {
+            Some(raw) => {
+                let raw_path = std::path::PathBuf::from(raw);
+                if raw_path.is_absolute() {
+                    raw_path
+                } else {
+                    std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(raw_path)
+                }
+            }
+            None => {
+                let asset_root = std::path::PathBuf::from(self.asset_root_resolved_or_default());
+                asset_root
+                    .parent()
+                    .map(std::path::Path::to_path_buf)
+                    .unwrap_or(asset_root)
+            }
+        };
+
+        project_root.join(".editor")
+    }
+
     fn asset_root_resolved_or_default(&self) -> String {
         let raw = self
             .asset_path
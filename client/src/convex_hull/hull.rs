@@ -0,0 +1,513 @@
+//! Quickhull over a point cloud.
+//!
+//! Starts from the largest tetrahedron it can find among the extreme points and gives every
+//! other point to a face it lies outside of. Then, while any face has points outside it, the
+//! farthest one is added: the faces it can see are removed, it is joined to their horizon, and
+//! their outside points go to the new faces (or are dropped, being inside now). Points are
+//! deduplicated first, since glTF meshes repeat vertices along UV seams.
+
+use bevy::{
+    math::{DVec3, Vec3},
+    platform::collections::{HashMap, HashSet},
+};
+
+/// Tolerance for "outside a face", relative to the size of the point cloud. The hull is built
+/// in f64; f32 planes through long, thin triangles are too coarse to keep the mesh closed.
+const RELATIVE_EPSILON: f64 = 1e-9;
+
+/// A closed triangle mesh around a point cloud, wound counter-clockwise seen from outside.
+#[derive(Debug, Clone, Default)]
+pub struct Hull {
+    pub points: Vec<Vec3>,
+    pub triangles: Vec<[u32; 3]>,
+}
+
+struct Face {
+    vertices: [usize; 3],
+    normal: DVec3,
+    offset: f64,
+    /// Points outside this face that no earlier face claimed.
+    outside: Vec<usize>,
+    removed: bool,
+}
+
+impl Face {
+    fn new(points: &[DVec3], vertices: [usize; 3]) -> Self {
+        let [a, b, c] = vertices.map(|i| points[i]);
+        // Degenerate slivers get a zero normal, so nothing ever sees them.
+        let normal = (b - a).cross(c - a).normalize_or_zero();
+        Self {
+            vertices,
+            normal,
+            offset: normal.dot(a),
+            outside: Vec::new(),
+            removed: false,
+        }
+    }
+
+    fn distance(&self, point: DVec3) -> f64 {
+        self.normal.dot(point) - self.offset
+    }
+
+    fn edges(&self) -> [(usize, usize); 3] {
+        let [a, b, c] = self.vertices;
+        [(a, b), (b, c), (c, a)]
+    }
+}
+
+/// The faces built so far, with a lookup from each directed edge to the face it belongs to.
+#[derive(Default)]
+struct Polytope {
+    faces: Vec<Face>,
+    by_edge: HashMap<(usize, usize), usize>,
+}
+
+impl Polytope {
+    fn add(&mut self, face: Face) -> usize {
+        let f = self.faces.len();
+        for edge in face.edges() {
+            self.by_edge.insert(edge, f);
+        }
+        self.faces.push(face);
+        f
+    }
+
+    fn remove(&mut self, f: usize) -> Vec<usize> {
+        for edge in self.faces[f].edges() {
+            self.by_edge.remove(&edge);
+        }
+        self.faces[f].removed = true;
+        std::mem::take(&mut self.faces[f].outside)
+    }
+
+    /// The face across `edge` of the face that has it.
+    fn neighbor(&self, (a, b): (usize, usize)) -> Option<usize> {
+        self.by_edge.get(&(b, a)).copied()
+    }
+
+    /// Give `point` to the first of `faces` it lies outside of, if any.
+    fn assign(&mut self, faces: &[usize], point: usize, points: &[DVec3], epsilon: f64) {
+        if let Some(f) = faces
+            .iter()
+            .copied()
+            .find(|f| self.faces[*f].distance(points[point]) > epsilon)
+        {
+            self.faces[f].outside.push(point);
+        }
+    }
+}
+
+/// The convex hull of `points`, with at most `max_vertices` vertices if given (never fewer
+/// than 4). Fails if there are too few points or they are all on one plane.
+pub fn convex_hull(points: &[Vec3], max_vertices: Option<usize>) -> Result<Hull, String> {
+    let hull = build(points)?;
+    match max_vertices.map(|max| max.max(4)) {
+        Some(max) if hull.points.len() > max => build(&farthest_points(&hull, max)?),
+        _ => Ok(hull),
+    }
+}
+
+fn build(points: &[Vec3]) -> Result<Hull, String> {
+    let mut seen = HashSet::new();
+    let points: Vec<DVec3> = points
+        .iter()
+        .filter(|p| p.is_finite() && seen.insert(p.to_array().map(f32::to_bits)))
+        .map(|p| p.as_dvec3())
+        .collect();
+
+    let epsilon = extent(&points) * RELATIVE_EPSILON;
+    let simplex = initial_simplex(&points, epsilon)?;
+
+    let centroid = simplex.iter().map(|i| points[*i]).sum::<DVec3>() / 4.0;
+    let [a, b, c, d] = simplex;
+    let mut polytope = Polytope::default();
+    let mut initial = Vec::with_capacity(4);
+    for [a, b, c] in [[a, b, c], [a, b, d], [a, c, d], [b, c, d]] {
+        let face = Face::new(&points, [a, b, c]);
+        let face = if face.distance(centroid) > 0.0 {
+            Face::new(&points, [a, c, b])
+        } else {
+            face
+        };
+        initial.push(polytope.add(face));
+    }
+    for point in (0..points.len()).filter(|i| !simplex.contains(i)) {
+        polytope.assign(&initial, point, &points, epsilon);
+    }
+
+    let mut pending = initial;
+    while let Some(seed) = pending.pop() {
+        if polytope.faces[seed].removed || polytope.faces[seed].outside.is_empty() {
+            continue;
+        }
+        let face = &polytope.faces[seed];
+        let (slot, &apex) = face
+            .outside
+            .iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| {
+                face.distance(points[**a])
+                    .total_cmp(&face.distance(points[**b]))
+            })
+            .expect("non-empty");
+        let apex_point = points[apex];
+        polytope.faces[seed].outside.swap_remove(slot);
+
+        // Grow the visible region across edges from the face the apex was taken from.
+        let mut visible = vec![seed];
+        let mut in_region = HashSet::new();
+        in_region.insert(seed);
+        let mut next = 0;
+        while next < visible.len() {
+            for edge in polytope.faces[visible[next]].edges() {
+                if let Some(neighbor) = polytope.neighbor(edge)
+                    && !in_region.contains(&neighbor)
+                    && polytope.faces[neighbor].distance(apex_point) > epsilon
+                {
+                    in_region.insert(neighbor);
+                    visible.push(neighbor);
+                }
+            }
+            next += 1;
+        }
+
+        // Edges of the visible region whose other side stays on the hull.
+        let horizon: Vec<(usize, usize)> = visible
+            .iter()
+            .flat_map(|f| polytope.faces[*f].edges())
+            .filter(|edge| {
+                polytope
+                    .neighbor(*edge)
+                    .is_none_or(|n| !in_region.contains(&n))
+            })
+            .collect();
+
+        // A region touching itself at a vertex has no single horizon loop, and joining it to
+        // the apex would tear the polytope. Such apexes are within round-off of the hull anyway.
+        let mut starts = HashSet::new();
+        if !horizon.iter().all(|(a, _)| starts.insert(*a)) {
+            pending.push(seed);
+            continue;
+        }
+
+        let orphans: Vec<usize> = visible.iter().flat_map(|f| polytope.remove(*f)).collect();
+        let new_faces: Vec<usize> = horizon
+            .into_iter()
+            .map(|(a, b)| polytope.add(Face::new(&points, [a, b, apex])))
+            .collect();
+        // Orphans outside none of the new faces are inside the hull now.
+        for point in orphans {
+            polytope.assign(&new_faces, point, &points, epsilon);
+        }
+        pending.extend(new_faces);
+    }
+
+    // Keep only the points the faces use, in first-use order.
+    let mut remap = HashMap::new();
+    let mut hull = Hull::default();
+    for face in polytope.faces.iter().filter(|face| !face.removed) {
+        let triangle = face.vertices.map(|i| {
+            *remap.entry(i).or_insert_with(|| {
+                hull.points.push(points[i].as_vec3());
+                (hull.points.len() - 1) as u32
+            })
+        });
+        hull.triangles.push(triangle);
+    }
+    Ok(hull)
+}
+
+/// Length of the bounding box diagonal.
+fn extent(points: &[DVec3]) -> f64 {
+    let (min, max) = points.iter().fold(
+        (DVec3::splat(f64::INFINITY), DVec3::splat(f64::NEG_INFINITY)),
+        |(min, max), p| (min.min(*p), max.max(*p)),
+    );
+    (max - min).length()
+}
+
+/// Four points spanning as much volume as a quick search finds.
+fn initial_simplex(points: &[DVec3], epsilon: f64) -> Result<[usize; 4], String> {
+    if points.len() < 4 {
+        return Err(format!(
+            "A convex hull needs at least 4 distinct vertices, found {}",
+            points.len()
+        ));
+    }
+
+    // The two farthest-apart of the axis extremes.
+    let mut extremes = Vec::with_capacity(6);
+    for axis in 0..3 {
+        extremes.push(farthest(points.len(), |i| -points[i][axis]));
+        extremes.push(farthest(points.len(), |i| points[i][axis]));
+    }
+    let (a, b) = extremes
+        .iter()
+        .flat_map(|a| extremes.iter().map(move |b| (*a, *b)))
+        .max_by(|(a1, b1), (a2, b2)| {
+            let d1 = points[*a1].distance_squared(points[*b1]);
+            let d2 = points[*a2].distance_squared(points[*b2]);
+            d1.total_cmp(&d2)
+        })
+        .expect("six extremes");
+
+    let line = (points[b] - points[a]).normalize_or_zero();
+    let line_distance = |i: usize| {
+        let offset = points[i] - points[a];
+        (offset - line * offset.dot(line)).length()
+    };
+    let c = farthest(points.len(), line_distance);
+    if line_distance(c) <= epsilon {
+        return Err("The mesh is degenerate: all its vertices lie on a line".into());
+    }
+
+    let normal = (points[b] - points[a])
+        .cross(points[c] - points[a])
+        .normalize_or_zero();
+    let plane_distance = |i: usize| normal.dot(points[i] - points[a]).abs();
+    let d = farthest(points.len(), plane_distance);
+    if plane_distance(d) <= epsilon {
+        return Err("The mesh is flat: all its vertices lie on one plane".into());
+    }
+
+    Ok([a, b, c, d])
+}
+
+/// The index below `count` with the largest `distance`.
+fn farthest(count: usize, distance: impl Fn(usize) -> f64) -> usize {
+    (0..count)
+        .max_by(|a, b| distance(*a).total_cmp(&distance(*b)))
+        .expect("at least one point")
+}
+
+/// `count` of the hull's points, spread out: the initial simplex, then repeatedly whichever
+/// point is farthest from all chosen so far. Each is a vertex of the full hull, so it stays
+/// one in the hull of the subset.
+fn farthest_points(hull: &Hull, count: usize) -> Result<Vec<Vec3>, String> {
+    let points: Vec<DVec3> = hull.points.iter().map(|p| p.as_dvec3()).collect();
+    let epsilon = extent(&points) * RELATIVE_EPSILON;
+
+    // Squared distance from each point to the nearest chosen one.
+    let mut nearest = vec![f64::INFINITY; points.len()];
+    let mut chosen = initial_simplex(&points, epsilon)?.to_vec();
+    for index in &chosen {
+        update_nearest(&mut nearest, &points, points[*index]);
+    }
+    while chosen.len() < count {
+        let next = farthest(points.len(), |i| nearest[i]);
+        update_nearest(&mut nearest, &points, points[next]);
+        chosen.push(next);
+    }
+    Ok(chosen.into_iter().map(|i| hull.points[i]).collect())
+}
+
+fn update_nearest(nearest: &mut [f64], points: &[DVec3], chosen: DVec3) {
+    for (distance, point) in nearest.iter_mut().zip(points) {
+        *distance = distance.min(point.distance_squared(chosen));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CORNERS: [Vec3; 8] = [
+        Vec3::new(-1.0, -1.0, -1.0),
+        Vec3::new(1.0, -1.0, -1.0),
+        Vec3::new(-1.0, 1.0, -1.0),
+        Vec3::new(1.0, 1.0, -1.0),
+        Vec3::new(-1.0, -1.0, 1.0),
+        Vec3::new(1.0, -1.0, 1.0),
+        Vec3::new(-1.0, 1.0, 1.0),
+        Vec3::new(1.0, 1.0, 1.0),
+    ];
+
+    /// Points spread over and inside a unit sphere, the same on every run.
+    fn sphere_cloud(count: usize) -> Vec<Vec3> {
+        let mut state = 0x2545_f491_4f6c_dd1du64;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state >> 40) as f32 / (1u64 << 24) as f32 * 2.0 - 1.0
+        };
+        (0..count)
+            .map(|i| {
+                let direction = Vec3::new(next(), next(), next()).normalize_or(Vec3::X);
+                // Every other point sits inside, so most of the cloud isn't on the hull.
+                if i % 2 == 0 {
+                    direction
+                } else {
+                    direction * next().abs()
+                }
+            })
+            .collect()
+    }
+
+    /// Every edge is shared by exactly two triangles that run along it in opposite
+    /// directions, every triangle faces away from the middle, and V - E + F = 2.
+    fn assert_closed_and_outward(hull: &Hull) {
+        let mut directed = HashMap::new();
+        for triangle in &hull.triangles {
+            let [a, b, c] = *triangle;
+            for edge in [(a, b), (b, c), (c, a)] {
+                *directed.entry(edge).or_insert(0) += 1;
+            }
+        }
+        for (&(a, b), &count) in &directed {
+            assert_eq!(count, 1, "edge ({a}, {b}) is used {count} times");
+            assert_eq!(
+                directed.get(&(b, a)),
+                Some(&1),
+                "edge ({a}, {b}) has no opposite"
+            );
+        }
+
+        let edges = directed.len() / 2;
+        let euler = hull.points.len() + hull.triangles.len() - edges;
+        assert_eq!(euler, 2, "not a closed surface of genus 0");
+
+        let middle = hull.points.iter().sum::<Vec3>() / hull.points.len() as f32;
+        for triangle in &hull.triangles {
+            let [a, b, c] = triangle.map(|i| hull.points[i as usize]);
+            let normal = (b - a).cross(c - a);
+            assert!(
+                normal.dot((a + b + c) / 3.0 - middle) > 0.0,
+                "triangle {triangle:?} faces inwards"
+            );
+        }
+    }
+
+    /// No point of `points` lies outside the hull.
+    fn assert_contains(hull: &Hull, points: &[Vec3]) {
+        for triangle in &hull.triangles {
+            let [a, b, c] = triangle.map(|i| hull.points[i as usize].as_dvec3());
+            let normal = (b - a).cross(c - a).normalize();
+            for point in points {
+                let distance = normal.dot(point.as_dvec3() - a);
+                assert!(distance < 1e-5, "{point} is {distance} outside the hull");
+            }
+        }
+    }
+
+    /// The checks the backend's `ConvexHull::validate` runs before storing a hull.
+    fn assert_backend_accepts(hull: &Hull) {
+        assert!(hull.points.len() >= 4, "{} points", hull.points.len());
+        assert!(
+            hull.triangles.len() >= 4,
+            "{} triangles",
+            hull.triangles.len()
+        );
+        assert!(hull.points.iter().all(|point| point.is_finite()));
+        for [a, b, c] in &hull.triangles {
+            assert!([a, b, c].iter().all(|i| (**i as usize) < hull.points.len()));
+            assert!(
+                a != b && b != c && c != a,
+                "({a}, {b}, {c}) repeats a vertex"
+            );
+        }
+    }
+
+    #[test]
+    fn cube_ignores_interior_duplicate_and_face_points() {
+        let mut points = Vec::new();
+        for corner in CORNERS {
+            points.push(corner);
+            points.push(corner);
+            points.push(corner * 0.5);
+        }
+        // On the faces and edges, but not corners, so they add nothing to the hull.
+        points.extend([Vec3::X, Vec3::NEG_Y, Vec3::Z, Vec3::new(1.0, 1.0, 0.0)]);
+        points.push(Vec3::ZERO);
+
+        let hull = convex_hull(&points, None).unwrap();
+
+        assert_eq!(hull.points.len(), 8);
+        assert_eq!(hull.triangles.len(), 12);
+        assert!(hull.points.iter().all(|point| CORNERS.contains(point)));
+        assert_closed_and_outward(&hull);
+        assert_contains(&hull, &points);
+        assert_backend_accepts(&hull);
+    }
+
+    #[test]
+    fn skips_non_finite_points() {
+        let mut points = CORNERS.to_vec();
+        points.push(Vec3::new(f32::NAN, 0.0, 0.0));
+        points.push(Vec3::splat(f32::INFINITY));
+
+        let hull = convex_hull(&points, None).unwrap();
+        assert_eq!(hull.points.len(), 8);
+        assert_closed_and_outward(&hull);
+    }
+
+    #[test]
+    fn sphere_cloud_is_closed_and_contains_every_point() {
+        let points = sphere_cloud(2000);
+
+        let hull = convex_hull(&points, None).unwrap();
+
+        assert!(hull.points.len() > 100, "{} vertices", hull.points.len());
+        assert_closed_and_outward(&hull);
+        assert_contains(&hull, &points);
+        assert_backend_accepts(&hull);
+    }
+
+    #[test]
+    fn max_vertices_caps_the_hull() {
+        let points = sphere_cloud(2000);
+
+        for max in [4, 5, 16, 64] {
+            let hull = convex_hull(&points, Some(max)).unwrap();
+            assert!(hull.points.len() <= max, "{} > {max}", hull.points.len());
+            assert!(hull.points.iter().all(|point| points.contains(point)));
+            assert_closed_and_outward(&hull);
+            assert_backend_accepts(&hull);
+        }
+    }
+
+    #[test]
+    fn max_vertices_below_a_tetrahedron_still_encloses_a_volume() {
+        let hull = convex_hull(&CORNERS, Some(1)).unwrap();
+        assert_eq!(hull.points.len(), 4);
+        assert_closed_and_outward(&hull);
+        assert_backend_accepts(&hull);
+    }
+
+    #[test]
+    fn max_vertices_above_the_hull_changes_nothing() {
+        let hull = convex_hull(&CORNERS, Some(100)).unwrap();
+        assert_eq!(hull.points.len(), 8);
+    }
+
+    #[test]
+    fn rejects_flat_point_sets() {
+        let points: Vec<Vec3> = (0..25)
+            .map(|i| Vec3::new((i % 5) as f32, (i / 5) as f32 * 0.5, 2.0))
+            .collect();
+
+        let err = convex_hull(&points, None).unwrap_err();
+        assert!(err.contains("flat"), "{err}");
+    }
+
+    #[test]
+    fn rejects_collinear_point_sets() {
+        let points: Vec<Vec3> = (0..10)
+            .map(|i| Vec3::new(1.0, 2.0, 3.0) * i as f32)
+            .collect();
+
+        let err = convex_hull(&points, None).unwrap_err();
+        assert!(err.contains("line"), "{err}");
+    }
+
+    #[test]
+    fn rejects_fewer_than_four_distinct_points() {
+        let points = [Vec3::ZERO, Vec3::X, Vec3::Y, Vec3::X, Vec3::ZERO];
+
+        let err = convex_hull(&points, None).unwrap_err();
+        assert!(
+            err.contains("at least 4 distinct vertices, found 3"),
+            "{err}"
+        );
+    }
+}
@@ -0,0 +1,145 @@
+//! Convex hull colliders generated from the meshes of glTF-backed objects.
+//!
+//! The details panel sends [`GenerateConvexHull`] for the selected object. `generate` gathers
+//! the vertex positions of every mesh the object's scene has spawned, in the object's local
+//! space, and wraps them in a hull (see `hull`). The result waits in [`HullPreview`], drawn by
+//! `colliders`, until the panel stores it through `set_collision_shape` or discards it.
+
+mod hull;
+
+use bevy::prelude::*;
+
+use crate::{
+    colliders::ColliderWireframe,
+    module_bindings::{CollisionShape, ConvexHull, Triangle},
+    selection::Selection,
+    world_object::WorldObjectIndex,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_message::<GenerateConvexHull>();
+    app.init_resource::<HullPreview>();
+    app.add_systems(Update, (discard_stale_preview, generate).chain());
+}
+
+/// Ask for a convex hull around object `id`'s loaded meshes, with at most `max_vertices`
+/// vertices if given.
+#[derive(Message, Debug, Clone, Copy)]
+pub struct GenerateConvexHull {
+    pub id: u64,
+    pub max_vertices: Option<usize>,
+}
+
+/// The last generated hull, until it's applied or discarded. Only kept while its object is
+/// the whole selection.
+#[derive(Resource, Default)]
+pub struct HullPreview(pub Option<PendingHull>);
+
+pub struct PendingHull {
+    pub id: u64,
+    /// The hull, or why none could be generated.
+    pub result: Result<GeneratedHull, String>,
+}
+
+pub struct GeneratedHull {
+    pub shape: CollisionShape,
+    pub wireframe: ColliderWireframe,
+    pub point_count: usize,
+    pub triangle_count: usize,
+}
+
+impl GeneratedHull {
+    fn new(hull: hull::Hull) -> Self {
+        let point_count = hull.points.len();
+        let triangle_count = hull.triangles.len();
+        let shape = CollisionShape::ConvexHull(ConvexHull {
+            points: hull.points.into_iter().map(Into::into).collect(),
+            indices: hull
+                .triangles
+                .into_iter()
+                .map(|[v_1, v_2, v_3]| Triangle { v_1, v_2, v_3 })
+                .collect(),
+        });
+        Self {
+            wireframe: ColliderWireframe::new(&shape),
+            shape,
+            point_count,
+            triangle_count,
+        }
+    }
+}
+
+fn discard_stale_preview(selection: Res<Selection>, mut preview: ResMut<HullPreview>) {
+    if let Some(pending) = &preview.0
+        && selection.ids() != [pending.id]
+    {
+        preview.0 = None;
+    }
+}
+
+/// Hulls are computed right away; quickhull handles the vertex counts of typical props within
+/// a frame or two.
+fn generate(
+    mut requests: MessageReader<GenerateConvexHull>,
+    mut preview: ResMut<HullPreview>,
+    index: Res<WorldObjectIndex>,
+    children: Query<&Children>,
+    transforms: Query<&GlobalTransform>,
+    mesh_instances: Query<(&Mesh3d, &GlobalTransform)>,
+    meshes: Res<Assets<Mesh>>,
+) {
+    // Only the latest request matters.
+    let Some(request) = requests.read().last().copied() else {
+        return;
+    };
+
+    let result = index
+        .get(request.id)
+        .ok_or_else(|| format!("Object {} isn't rendered", request.id))
+        .and_then(|root| {
+            local_mesh_positions(root, &children, &transforms, &mesh_instances, &meshes)
+        })
+        .and_then(|points| hull::convex_hull(&points, request.max_vertices))
+        .map(GeneratedHull::new);
+
+    preview.0 = Some(PendingHull {
+        id: request.id,
+        result,
+    });
+}
+
+/// Every vertex position of every loaded mesh under `root`, in `root`'s local space.
+fn local_mesh_positions(
+    root: Entity,
+    children: &Query<&Children>,
+    transforms: &Query<&GlobalTransform>,
+    mesh_instances: &Query<(&Mesh3d, &GlobalTransform)>,
+    meshes: &Assets<Mesh>,
+) -> Result<Vec<Vec3>, String> {
+    let root_transform = transforms
+        .get(root)
+        .map_err(|_| "The object has no transform yet".to_string())?;
+    let to_local = root_transform.affine().inverse();
+
+    let mut points = Vec::new();
+    for (mesh, transform) in children
+        .iter_descendants(root)
+        .filter_map(|entity| mesh_instances.get(entity).ok())
+    {
+        let Some(positions) = meshes
+            .get(&mesh.0)
+            .and_then(|mesh| mesh.attribute(Mesh::ATTRIBUTE_POSITION))
+            .and_then(|positions| positions.as_float3())
+        else {
+            continue;
+        };
+        points.extend(positions.iter().map(|position| {
+            to_local.transform_point3(transform.transform_point(Vec3::from(*position)))
+        }));
+    }
+
+    if points.is_empty() {
+        return Err("The object's meshes haven't loaded yet".into());
+    }
+    Ok(points)
+}
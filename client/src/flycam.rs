@@ -4,7 +4,7 @@ use bevy::{
     color::Color,
     ecs::{
         component::Component,
-        message::MessageReader,
+        message::{Message, MessageReader},
         query::With,
         resource::Resource,
         system::{Commands, Res, ResMut, Single},
@@ -28,6 +28,7 @@ use bevy_egui::EguiContexts;
 pub(super) fn plugin(app: &mut App) {
     app.init_resource::<FlyCamSettings>();
     app.init_resource::<FlyCamActive>();
+    app.add_message::<FocusCamera>();
 
     app.add_systems(Startup, spawn_camera);
 
@@ -43,6 +44,7 @@ pub(super) fn plugin(app: &mut App) {
             flycam_move.run_if(flycam_is_active),
             flycam_pan.run_if(flycam_pan_is_active),
             flycam_scroll_zoom,
+            apply_focus,
         ),
     );
 }
@@ -151,6 +153,27 @@ fn spawn_camera(mut commands: Commands) {
     ));
 }
 
+/// Ask the flycam to frame a point: it keeps its orientation and moves back from `target`
+/// along its view direction until it is `distance` away.
+#[derive(Message, Debug, Clone, Copy)]
+pub struct FocusCamera {
+    pub target: Vec3,
+    pub distance: f32,
+}
+
+fn apply_focus(
+    mut focus: MessageReader<FocusCamera>,
+    mut flycam_transform: Single<&mut Transform, With<FlyCam>>,
+) {
+    // Only the latest request matters.
+    let Some(focus) = focus.read().last() else {
+        return;
+    };
+
+    let forward = flycam_transform.forward();
+    flycam_transform.translation = focus.target - forward * focus.distance;
+}
+
 fn flycam_is_active(active: Res<FlyCamActive>) -> bool {
     active.0
 }
@@ -0,0 +1,243 @@
+//! Pointer observers on the gizmo handles.
+//!
+//! A drag moves every selected object relative to its transform at drag start, using the
+//! frame the gizmo had when the handle was grabbed. Nothing is sent while dragging; on release
+//! the changed components are saved through the same reducers as the object drag tools.
+//!
+//! Snapping applies along the dragged axes of the gizmo frame: translation lines the last
+//! selected object up with the increment, rotation snaps the angle and scale the grabbed
+//! component.
+
+use bevy::prelude::*;
+use bevy_egui::EguiContexts;
+
+use super::{Frame, GizmoFrame, GizmoHandle, GizmoState, picking::constraint_point};
+use crate::{
+    flycam::{FlyCam, FlyCamActive},
+    locks::{LockRequests, editor_name, first_locked_by_other},
+    pending_ops::EditorOps,
+    selection::Selection,
+    snapshot::SyncState,
+    spacetimedb::SpacetimeDB,
+    ui::transform_tools::{Snapping, TransformTool},
+    world_object::{ObjectId, WorldObjectIndex, save_changed_transforms, selected_transforms},
+};
+
+/// Smallest scale component magnitude a scale drag can produce.
+const MIN_SCALE: f32 = 0.001;
+
+/// A handle drag in progress.
+pub(super) struct GizmoDrag {
+    pub(super) handle: GizmoHandle,
+    /// The gizmo frame at drag start.
+    pub(super) frame: Frame,
+    /// The grabbed point on the handle's constraint (see `constraint_point`).
+    grab: Vec3,
+    /// Every selected object's entity, row id and transform at drag start.
+    start: Vec<(Entity, u64, Transform)>,
+}
+
+pub(super) fn on_over(
+    over: On<Pointer<Over>>,
+    handles: Query<&GizmoHandle>,
+    mut state: ResMut<GizmoState>,
+) {
+    if let Ok(handle) = handles.get(over.entity) {
+        state.hovered = Some(*handle);
+    }
+}
+
+pub(super) fn on_out(
+    out: On<Pointer<Out>>,
+    handles: Query<&GizmoHandle>,
+    mut state: ResMut<GizmoState>,
+) {
+    if handles.get(out.entity).ok().copied() == state.hovered {
+        state.hovered = None;
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(super) fn on_drag_start(
+    drag: On<Pointer<DragStart>>,
+    handles: Query<&GizmoHandle>,
+    frame: Res<GizmoFrame>,
+    selection: Res<Selection>,
+    index: Res<WorldObjectIndex>,
+    objects: Query<&Transform, With<ObjectId>>,
+    mut tool: ResMut<TransformTool>,
+    flycam_active: Res<FlyCamActive>,
+    sync: Res<SyncState>,
+    mut contexts: EguiContexts,
+    camera: Query<(&Camera, &GlobalTransform), With<FlyCam>>,
+    stdb: SpacetimeDB,
+    mut locks: ResMut<LockRequests>,
+    mut state: ResMut<GizmoState>,
+) {
+    if drag.button != PointerButton::Primary || tool.is_active || flycam_active.0 {
+        return;
+    }
+
+    // The drag would only end in a reducer call that gets refused, so don't move anything locally.
+    if !sync.allows_edits() {
+        return;
+    }
+
+    if let Ok(ctx) = contexts.ctx_mut()
+        && (ctx.is_pointer_over_area() || ctx.wants_pointer_input())
+    {
+        return;
+    }
+
+    let (Ok(handle), Some(frame)) = (handles.get(drag.entity), frame.0) else {
+        return;
+    };
+    let Ok((cam, cam_gt)) = camera.single() else {
+        return;
+    };
+    let Ok(ray) = cam.viewport_to_world(cam_gt, drag.pointer_location.position) else {
+        return;
+    };
+    let Some(grab) = constraint_point(*handle, &frame, ray) else {
+        return;
+    };
+
+    // Another editor is working on part of the selection; its edits win until it lets go.
+    if let Some((id, owner)) = first_locked_by_other(&stdb, selection.ids()) {
+        eprintln!("Object {id} is locked by {}", editor_name(&stdb, owner));
+        return;
+    }
+
+    tool.is_active = true;
+    locks.drag = selection.ids().to_vec();
+    state.drag = Some(GizmoDrag {
+        handle: *handle,
+        frame,
+        grab,
+        start: selected_transforms(&selection, &index, |entity| {
+            objects.get(entity).ok().copied()
+        }),
+    });
+}
+
+pub(super) fn on_drag(
+    drag: On<Pointer<Drag>>,
+    mut objects: Query<&mut Transform, With<ObjectId>>,
+    flycam_active: Res<FlyCamActive>,
+    camera: Query<(&Camera, &GlobalTransform), With<FlyCam>>,
+    snapping: Snapping,
+    state: Res<GizmoState>,
+) {
+    let Some(gizmo_drag) = &state.drag else {
+        return;
+    };
+    if flycam_active.0 {
+        return;
+    }
+
+    let Ok((cam, cam_gt)) = camera.single() else {
+        return;
+    };
+    let Ok(ray) = cam.viewport_to_world(cam_gt, drag.pointer_location.position) else {
+        return;
+    };
+    // Looking straight down an axis (or edge-on at a plane) has no usable grab point; keep
+    // the last pose until the view allows one again.
+    let Some(current) = constraint_point(gizmo_drag.handle, &gizmo_drag.frame, ray) else {
+        return;
+    };
+
+    apply_drag(gizmo_drag, current, &snapping, &mut objects);
+}
+
+pub(super) fn on_drag_end(
+    _drag: On<Pointer<DragEnd>>,
+    mut objects: Query<&mut Transform>,
+    mut ops: EditorOps,
+    mut tool: ResMut<TransformTool>,
+    flycam_active: Res<FlyCamActive>,
+    mut locks: ResMut<LockRequests>,
+    mut state: ResMut<GizmoState>,
+) {
+    let Some(gizmo_drag) = state.drag.take() else {
+        return;
+    };
+    tool.is_active = false;
+    locks.drag.clear();
+
+    // RMB took over the camera mid-drag; treat the drag as cancelled.
+    if flycam_active.0 {
+        for (entity, _, start) in &gizmo_drag.start {
+            if let Ok(mut transform) = objects.get_mut(*entity) {
+                *transform = *start;
+            }
+        }
+        return;
+    }
+
+    save_changed_transforms(&mut ops, &gizmo_drag.start, &objects.as_readonly());
+}
+
+/// Place every dragged object for the pointer now being at `current` on the handle's constraint.
+fn apply_drag(
+    drag: &GizmoDrag,
+    current: Vec3,
+    snapping: &Snapping,
+    objects: &mut Query<&mut Transform, With<ObjectId>>,
+) {
+    let frame = &drag.frame;
+    match drag.handle {
+        GizmoHandle::Translate(axis) | GizmoHandle::TranslatePlane(axis) => {
+            let axes = match drag.handle {
+                GizmoHandle::TranslatePlane(_) => !axis.mask(),
+                _ => axis.mask(),
+            };
+
+            let mut delta = current - drag.grab;
+            if let Some((_, _, reference)) = drag.start.last() {
+                let target = reference.translation + delta;
+                delta = snapping.translation(target, frame.rotation, axes) - reference.translation;
+            }
+            for (entity, _, start) in &drag.start {
+                if let Ok(mut transform) = objects.get_mut(*entity) {
+                    transform.translation = start.translation + delta;
+                }
+            }
+        }
+        GizmoHandle::Rotate(axis) => {
+            let normal = frame.axis(axis);
+            let from = drag.grab - frame.pivot;
+            let to = current - frame.pivot;
+            let angle = snapping.rotation(normal.dot(from.cross(to)).atan2(from.dot(to)));
+            let rotation = Quat::from_axis_angle(normal, angle);
+
+            // Multiple objects orbit the pivot; a single object rotates in place.
+            for (entity, _, start) in &drag.start {
+                if let Ok(mut transform) = objects.get_mut(*entity) {
+                    transform.rotation = rotation * start.rotation;
+                    transform.translation =
+                        frame.pivot + rotation * (start.translation - frame.pivot);
+                }
+            }
+        }
+        GizmoHandle::Scale(axis) => {
+            let dir = frame.axis(axis);
+            let from = (drag.grab - frame.pivot).dot(dir);
+            if from.abs() < f32::EPSILON {
+                return;
+            }
+            let factor = (current - frame.pivot).dot(dir) / from;
+
+            for (entity, _, start) in &drag.start {
+                if let Ok(mut transform) = objects.get_mut(*entity) {
+                    let mut scale = start.scale;
+                    let scaled = snapping.scale(scale[axis.index()] * factor);
+                    // Keep the sign so mirrored objects stay mirrored; the DB only rejects
+                    // components too close to zero.
+                    scale[axis.index()] = scaled.signum() * scaled.abs().max(MIN_SCALE);
+                    transform.scale = scale;
+                }
+            }
+        }
+    }
+}
@@ -1,5 +1,334 @@
-use bevy::prelude::*;
+//! Transform gizmo drawn at the selection pivot.
+//!
+//! Which handles are shown follows the selected transform tool:
+//! - Move: X/Y/Z arrows plus XY/XZ/YZ plane squares
+//! - Rotate: one ring per axis
+//! - Scale: one cube per axis, scaling that component of each object's local scale
+//!
+//! Move and rotate handles are oriented by the tool's World/Local space, where local means the
+//! last selected object's axes.
+//!
+//! Handles are colored like the infinite grid axes and drawn at a constant size on screen.
+//! They are plain entities without meshes: `picking` hit-tests them against the pointer ray
+//! and reports them to bevy_picking above regular meshes, so the usual `Pointer<...>` events
+//! reach the observers in `interaction`.
 
-pub(super) fn plugin(_app: &mut App) {
-    //
+mod interaction;
+mod picking;
+
+use bevy::{picking::PickingSystems, prelude::*, transform::TransformSystems};
+
+use crate::{
+    flycam::FlyCam,
+    infinite_grid::{X_AXIS_COLOR, Y_AXIS_COLOR, Z_AXIS_COLOR},
+    selection::Selection,
+    ui::transform_tools::{TransformSpace, TransformTool, TransformToolMode},
+    world_object::{ObjectId, WorldObjectIndex, selected_transforms, selection_pivot},
+};
+
+/// Gizmo size as a fraction of its distance to the camera, which keeps it constant on screen.
+const SCREEN_SIZE: f32 = 0.15;
+
+/// Handle layout, in gizmo units (multiples of `Frame::size`).
+const ARROW_START: f32 = 0.15;
+const ARROW_LENGTH: f32 = 1.0;
+const ARROW_TIP: f32 = 0.2;
+const PLANE_MIN: f32 = 0.25;
+const PLANE_MAX: f32 = 0.5;
+const RING_RADIUS: f32 = 1.0;
+const SCALE_HANDLE: f32 = 0.9;
+const SCALE_CUBE: f32 = 0.12;
+
+const HIGHLIGHT_COLOR: Color = Color::srgb(1.0, 0.85, 0.1);
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_gizmo_group::<TransformGizmos>();
+    app.init_resource::<GizmoFrame>();
+    app.init_resource::<GizmoState>();
+    app.add_systems(Startup, (configure_gizmos, spawn_handles));
+    app.add_systems(
+        PreUpdate,
+        picking::pick_handles.in_set(PickingSystems::Backend),
+    );
+    app.add_systems(
+        PostUpdate,
+        (update_frame, draw)
+            .chain()
+            .after(TransformSystems::Propagate),
+    );
+}
+
+/// Gizmo config group for the transform gizmo, so it can draw on top of the world.
+#[derive(Default, Reflect, GizmoConfigGroup)]
+struct TransformGizmos;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GizmoAxis {
+    X,
+    Y,
+    Z,
+}
+
+impl GizmoAxis {
+    const ALL: [GizmoAxis; 3] = [GizmoAxis::X, GizmoAxis::Y, GizmoAxis::Z];
+
+    fn unit(self) -> Vec3 {
+        match self {
+            GizmoAxis::X => Vec3::X,
+            GizmoAxis::Y => Vec3::Y,
+            GizmoAxis::Z => Vec3::Z,
+        }
+    }
+
+    /// Selects this axis' component, e.g. for snapping only along it.
+    fn mask(self) -> BVec3 {
+        BVec3::new(
+            self == GizmoAxis::X,
+            self == GizmoAxis::Y,
+            self == GizmoAxis::Z,
+        )
+    }
+
+    fn index(self) -> usize {
+        match self {
+            GizmoAxis::X => 0,
+            GizmoAxis::Y => 1,
+            GizmoAxis::Z => 2,
+        }
+    }
+
+    fn color(self) -> Color {
+        match self {
+            GizmoAxis::X => X_AXIS_COLOR,
+            GizmoAxis::Y => Y_AXIS_COLOR,
+            GizmoAxis::Z => Z_AXIS_COLOR,
+        }
+    }
+
+    /// The two axes spanning the plane this axis is the normal of.
+    fn others(self) -> (GizmoAxis, GizmoAxis) {
+        match self {
+            GizmoAxis::X => (GizmoAxis::Y, GizmoAxis::Z),
+            GizmoAxis::Y => (GizmoAxis::X, GizmoAxis::Z),
+            GizmoAxis::Z => (GizmoAxis::X, GizmoAxis::Y),
+        }
+    }
+}
+
+/// One pickable part of the gizmo.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+enum GizmoHandle {
+    /// Arrow moving along an axis.
+    Translate(GizmoAxis),
+    /// Square moving within the plane whose normal is the axis.
+    TranslatePlane(GizmoAxis),
+    /// Ring rotating around the axis.
+    Rotate(GizmoAxis),
+    /// Cube scaling along the axis.
+    Scale(GizmoAxis),
+}
+
+impl GizmoHandle {
+    fn tool(self) -> TransformToolMode {
+        match self {
+            GizmoHandle::Translate(_) | GizmoHandle::TranslatePlane(_) => {
+                TransformToolMode::Translate
+            }
+            GizmoHandle::Rotate(_) => TransformToolMode::Rotate,
+            GizmoHandle::Scale(_) => TransformToolMode::Scale,
+        }
+    }
+
+    fn color(self) -> Color {
+        match self {
+            GizmoHandle::Translate(axis)
+            | GizmoHandle::TranslatePlane(axis)
+            | GizmoHandle::Rotate(axis)
+            | GizmoHandle::Scale(axis) => axis.color(),
+        }
+    }
+}
+
+/// Where the gizmo is drawn this frame.
+#[derive(Debug, Clone, Copy)]
+struct Frame {
+    pivot: Vec3,
+    /// Orientation of the gizmo axes.
+    rotation: Quat,
+    /// World-space length of one gizmo unit.
+    size: f32,
+}
+
+impl Frame {
+    fn axis(&self, axis: GizmoAxis) -> Vec3 {
+        self.rotation * axis.unit()
+    }
+}
+
+/// The gizmo frame for the current selection; `None` when nothing is selected.
+#[derive(Resource, Default)]
+struct GizmoFrame(Option<Frame>);
+
+/// Hover and drag state of the gizmo handles.
+#[derive(Resource, Default)]
+struct GizmoState {
+    hovered: Option<GizmoHandle>,
+    drag: Option<interaction::GizmoDrag>,
+}
+
+impl GizmoState {
+    /// The handle to highlight: the one being dragged, otherwise the hovered one.
+    fn highlighted(&self) -> Option<GizmoHandle> {
+        self.drag.as_ref().map(|drag| drag.handle).or(self.hovered)
+    }
+}
+
+fn configure_gizmos(mut config_store: ResMut<GizmoConfigStore>) {
+    let (config, _) = config_store.config_mut::<TransformGizmos>();
+    config.line.width = 3.0;
+    // Keep the handles visible through the objects they are attached to.
+    config.depth_bias = -1.0;
+}
+
+fn spawn_handles(mut commands: Commands) {
+    for axis in GizmoAxis::ALL {
+        for handle in [
+            GizmoHandle::Translate(axis),
+            GizmoHandle::TranslatePlane(axis),
+            GizmoHandle::Rotate(axis),
+            GizmoHandle::Scale(axis),
+        ] {
+            commands
+                .spawn((Name::new(format!("Gizmo {handle:?}")), handle))
+                .observe(interaction::on_over)
+                .observe(interaction::on_out)
+                .observe(interaction::on_drag_start)
+                .observe(interaction::on_drag)
+                .observe(interaction::on_drag_end);
+        }
+    }
+}
+
+fn update_frame(
+    selection: Res<Selection>,
+    index: Res<WorldObjectIndex>,
+    objects: Query<&Transform, With<ObjectId>>,
+    tool: Res<TransformTool>,
+    camera: Query<&GlobalTransform, With<FlyCam>>,
+    state: Res<GizmoState>,
+    mut frame: ResMut<GizmoFrame>,
+) {
+    let selected = selected_transforms(&selection, &index, |entity| {
+        objects.get(entity).ok().copied()
+    });
+    let Ok(cam_gt) = camera.single() else {
+        frame.0 = None;
+        return;
+    };
+    if selected.is_empty() {
+        frame.0 = None;
+        return;
+    }
+
+    // Mid-drag the axes stay where they were grabbed, so rotating doesn't spin the rings.
+    let rotation = match &state.drag {
+        Some(drag) => drag.frame.rotation,
+        None => gizmo_rotation(&tool, &selected),
+    };
+
+    let pivot = selection_pivot(&selected);
+    frame.0 = Some(Frame {
+        pivot,
+        rotation,
+        size: cam_gt.translation().distance(pivot).max(0.01) * SCREEN_SIZE,
+    });
+}
+
+/// Move and rotate follow the tool's space; scale always acts on local axes. Local axes are
+/// those of the last selected object.
+fn gizmo_rotation(tool: &TransformTool, selected: &[(Entity, u64, Transform)]) -> Quat {
+    let local =
+        tool.space == TransformSpace::Local || tool.selected_tool == TransformToolMode::Scale;
+    if !local {
+        return Quat::IDENTITY;
+    }
+
+    selected
+        .last()
+        .map(|(_, _, transform)| transform.rotation)
+        .unwrap_or_default()
+}
+
+fn draw(
+    mut gizmos: Gizmos<TransformGizmos>,
+    frame: Res<GizmoFrame>,
+    tool: Res<TransformTool>,
+    state: Res<GizmoState>,
+) {
+    let Some(frame) = frame.0 else {
+        return;
+    };
+
+    let highlighted = state.highlighted();
+    let color = |handle: GizmoHandle| {
+        if highlighted == Some(handle) {
+            HIGHLIGHT_COLOR
+        } else {
+            handle.color()
+        }
+    };
+
+    let pivot = frame.pivot;
+    let size = frame.size;
+
+    for axis in GizmoAxis::ALL {
+        let dir = frame.axis(axis);
+        match tool.selected_tool {
+            TransformToolMode::Translate => {
+                let handle = GizmoHandle::Translate(axis);
+                gizmos
+                    .arrow(
+                        pivot + dir * ARROW_START * size,
+                        pivot + dir * ARROW_LENGTH * size,
+                        color(handle),
+                    )
+                    .with_tip_length(ARROW_TIP * size);
+
+                let handle = GizmoHandle::TranslatePlane(axis);
+                let (u, v) = axis.others();
+                let (u, v) = (frame.axis(u) * size, frame.axis(v) * size);
+                let corners = [
+                    pivot + u * PLANE_MIN + v * PLANE_MIN,
+                    pivot + u * PLANE_MAX + v * PLANE_MIN,
+                    pivot + u * PLANE_MAX + v * PLANE_MAX,
+                    pivot + u * PLANE_MIN + v * PLANE_MAX,
+                ];
+                gizmos.linestrip(
+                    corners.into_iter().chain(std::iter::once(corners[0])),
+                    color(handle),
+                );
+            }
+            TransformToolMode::Rotate => {
+                let handle = GizmoHandle::Rotate(axis);
+                gizmos
+                    .circle(
+                        Isometry3d::new(pivot, Quat::from_rotation_arc(Vec3::Z, dir)),
+                        RING_RADIUS * size,
+                        color(handle),
+                    )
+                    .resolution(64);
+            }
+            TransformToolMode::Scale => {
+                let handle = GizmoHandle::Scale(axis);
+                let end = pivot + dir * SCALE_HANDLE * size;
+                gizmos.line(pivot, end, color(handle));
+                gizmos.cuboid(
+                    Transform::from_translation(end)
+                        .with_rotation(frame.rotation)
+                        .with_scale(Vec3::splat(SCALE_CUBE * size)),
+                    color(handle),
+                );
+            }
+        }
+    }
 }
@@ -0,0 +1,126 @@
+//! Picking backend for the gizmo handles, plus the ray math the drag interaction shares.
+//!
+//! Handles have no meshes, so they're hit-tested analytically against each pointer ray. Hits
+//! are reported just above the camera's own order so a handle blocks the object behind it.
+
+use bevy::{
+    picking::backend::{HitData, PointerHits, ray::RayMap},
+    prelude::*,
+};
+
+use super::{
+    ARROW_LENGTH, ARROW_START, Frame, GizmoFrame, GizmoHandle, PLANE_MAX, PLANE_MIN, RING_RADIUS,
+    SCALE_CUBE, SCALE_HANDLE,
+};
+use crate::{flycam::FlyCam, ui::transform_tools::TransformTool};
+
+/// How close the ray must pass to a line-like handle, in gizmo units.
+const PICK_RADIUS: f32 = 0.06;
+
+pub(super) fn pick_handles(
+    ray_map: Res<RayMap>,
+    frame: Res<GizmoFrame>,
+    tool: Res<TransformTool>,
+    handles: Query<(Entity, &GizmoHandle)>,
+    cameras: Query<&Camera, With<FlyCam>>,
+    mut output: MessageWriter<PointerHits>,
+) {
+    let Some(frame) = frame.0 else {
+        return;
+    };
+
+    for (ray_id, ray) in ray_map.iter() {
+        let Ok(camera) = cameras.get(ray_id.camera) else {
+            continue;
+        };
+
+        let closest = handles
+            .iter()
+            .filter(|(_, handle)| handle.tool() == tool.selected_tool)
+            .filter_map(|(entity, handle)| Some((entity, hit_depth(*handle, &frame, *ray)?)))
+            .min_by(|(_, a), (_, b)| a.total_cmp(b));
+
+        let Some((entity, depth)) = closest else {
+            continue;
+        };
+
+        output.write(PointerHits::new(
+            ray_id.pointer,
+            vec![(entity, HitData::new(ray_id.camera, depth, None, None))],
+            camera.order as f32 + 0.5,
+        ));
+    }
+}
+
+/// Distance along `ray` at which it hits `handle`, if it does.
+fn hit_depth(handle: GizmoHandle, frame: &Frame, ray: Ray3d) -> Option<f32> {
+    let size = frame.size;
+    match handle {
+        GizmoHandle::Translate(axis) => {
+            let dir = frame.axis(axis);
+            let (along, _) = closest_on_line(ray, frame.pivot, dir)?;
+            let along = along.clamp(ARROW_START * size, ARROW_LENGTH * size);
+            near_point(ray, frame.pivot + dir * along, PICK_RADIUS * size)
+        }
+        GizmoHandle::TranslatePlane(axis) => {
+            let depth = ray.intersect_plane(frame.pivot, InfinitePlane3d::new(frame.axis(axis)))?;
+            let offset = ray.get_point(depth) - frame.pivot;
+            let (u, v) = axis.others();
+            let inside = |coord: f32| (PLANE_MIN * size..=PLANE_MAX * size).contains(&coord);
+            (inside(offset.dot(frame.axis(u))) && inside(offset.dot(frame.axis(v))))
+                .then_some(depth)
+        }
+        GizmoHandle::Rotate(axis) => {
+            let depth = ray.intersect_plane(frame.pivot, InfinitePlane3d::new(frame.axis(axis)))?;
+            let radius = ray.get_point(depth).distance(frame.pivot);
+            ((radius - RING_RADIUS * size).abs() < PICK_RADIUS * size).then_some(depth)
+        }
+        GizmoHandle::Scale(axis) => near_point(
+            ray,
+            frame.pivot + frame.axis(axis) * SCALE_HANDLE * size,
+            SCALE_CUBE * size,
+        ),
+    }
+}
+
+/// The point the pointer grabs on a handle's constraint: the closest point on its axis line for
+/// arrows and scale cubes, the hit point on its plane for squares and rings.
+pub(super) fn constraint_point(handle: GizmoHandle, frame: &Frame, ray: Ray3d) -> Option<Vec3> {
+    match handle {
+        GizmoHandle::Translate(axis) | GizmoHandle::Scale(axis) => {
+            let dir = frame.axis(axis);
+            let (along, _) = closest_on_line(ray, frame.pivot, dir)?;
+            Some(frame.pivot + dir * along)
+        }
+        GizmoHandle::TranslatePlane(axis) | GizmoHandle::Rotate(axis) => {
+            let depth = ray.intersect_plane(frame.pivot, InfinitePlane3d::new(frame.axis(axis)))?;
+            Some(ray.get_point(depth))
+        }
+    }
+}
+
+/// Parameters `(along line, along ray)` of the closest points between the line
+/// `origin + s * dir` and `ray`, or `None` when they're (nearly) parallel or behind the camera.
+fn closest_on_line(ray: Ray3d, origin: Vec3, dir: Vec3) -> Option<(f32, f32)> {
+    let ray_dir = *ray.direction;
+    let w = ray.origin - origin;
+    let b = ray_dir.dot(dir);
+    let denom = 1.0 - b * b;
+    if denom < 1e-6 {
+        return None;
+    }
+
+    let along = (dir.dot(w) - b * ray_dir.dot(w)) / denom;
+    let depth = along * b - ray_dir.dot(w);
+    (depth > 0.0).then_some((along, depth))
+}
+
+/// Distance along `ray` to the point nearest `point`, if the ray passes within `radius` of it.
+fn near_point(ray: Ray3d, point: Vec3, radius: f32) -> Option<f32> {
+    let depth = (point - ray.origin).dot(*ray.direction);
+    if depth <= 0.0 {
+        return None;
+    }
+
+    (ray.get_point(depth).distance(point) < radius).then_some(depth)
+}
@@ -42,8 +42,12 @@ impl Default for InfiniteGridEnabled {
 
 const GRID_SHADER_HANDLE: Handle<Shader> = uuid_handle!("c7f0c7a8-03a2-4c25-9b31-17c7f02b99b7");
 const GRID_SHADER_ASSET_PATH: &str = "infinite_grid.wgsl";
-const X_AXIS_COLOR: Color = Color::srgb(1.0, 0.2, 0.2);
-const Z_AXIS_COLOR: Color = Color::srgb(0.2, 0.2, 1.0);
+/// Distance between minor grid lines, in meters. Translation snapping defaults to it.
+pub const GRID_MINOR_SPACING: f32 = 1.0;
+pub const X_AXIS_COLOR: Color = Color::srgb(1.0, 0.2, 0.2);
+pub const Z_AXIS_COLOR: Color = Color::srgb(0.2, 0.2, 1.0);
+/// The grid lies in XZ and never draws a Y axis; the transform gizmo still needs its color.
+pub const Y_AXIS_COLOR: Color = Color::srgb(0.2, 0.9, 0.2);
 
 pub(super) fn plugin(app: &mut App) {
     app.init_resource::<InfiniteGridEnabled>();
@@ -203,7 +207,8 @@ struct GridDisplaySettingsUniform {
 impl GridDisplaySettingsUniform {
     fn from_settings(settings: &InfiniteGridSettings) -> Self {
         Self {
-            scale: 1.0,
+            // The shader's scale is lines per meter.
+            scale: 1.0 / GRID_MINOR_SPACING,
             dist_fadeout_const: 1.0 / settings.fadeout_distance.max(0.0001),
             // Keep the existing WGSL behavior: angle-based fade factor.
             dot_fadeout_const: 1.0 / 0.25,
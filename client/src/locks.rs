@@ -0,0 +1,127 @@
+//! Object locks through the `object_lock` table.
+//!
+//! Systems that are about to edit objects put their ids in [`LockRequests`]: drags for their
+//! duration, the details panel for whatever it shows. `sync_locks` turns the wanted set into
+//! `acquire_locks`/`release_locks` calls and renews leases before they run out. Objects locked
+//! by someone else are never requested; the DB would refuse them anyway.
+
+use core::time::Duration;
+
+use bevy::{
+    platform::collections::{HashMap, HashSet},
+    prelude::*,
+};
+use spacetimedb_sdk::{Identity, Table, Timestamp};
+
+use crate::{
+    module_bindings::{
+        EditorPresenceTableAccess, ObjectLockTableAccess, acquire_locks, release_locks,
+    },
+    spacetimedb::SpacetimeDB,
+};
+
+/// Minimum time between lock syncs, unless the requests changed.
+const LOCK_SYNC_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Leases with less than this left are renewed. The DB grants 30 seconds per acquire.
+const LOCK_RENEW_BEFORE: Duration = Duration::from_secs(10);
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<LockRequests>();
+    app.add_systems(PostUpdate, sync_locks);
+}
+
+/// Objects this editor wants locked, by who needs them.
+#[derive(Resource, Default, Debug)]
+pub struct LockRequests {
+    /// Objects being dragged.
+    pub drag: Vec<u64>,
+    /// Objects shown in the details panel.
+    pub details: Vec<u64>,
+}
+
+/// The identity holding an unexpired lock on `id`, unless it's this editor.
+pub fn locked_by_other(stdb: &SpacetimeDB, id: u64) -> Option<Identity> {
+    let lock = stdb.db().object_lock().object_id().find(&id)?;
+    (Some(lock.owner) != stdb.try_identity() && lock.expires_at > Timestamp::now())
+        .then_some(lock.owner)
+}
+
+/// The first of `ids` someone else holds a lock on, with the holder.
+pub fn first_locked_by_other(stdb: &SpacetimeDB, ids: &[u64]) -> Option<(u64, Identity)> {
+    ids.iter()
+        .find_map(|id| locked_by_other(stdb, *id).map(|owner| (*id, owner)))
+}
+
+/// The name another editor goes by: its presence display name, or its abbreviated identity.
+pub fn editor_name(stdb: &SpacetimeDB, identity: Identity) -> String {
+    stdb.db()
+        .editor_presence()
+        .identity()
+        .find(&identity)
+        .map(|presence| presence.display_name)
+        .unwrap_or_else(|| identity.to_abbreviated_hex().to_string())
+}
+
+/// Acquire, renew and release locks so this editor holds exactly the requested ones.
+///
+/// Runs in `PostUpdate`, after drags have sent their final reducer calls, so a release always
+/// reaches the DB after the edits it was protecting.
+fn sync_locks(
+    time: Res<Time>,
+    requests: Res<LockRequests>,
+    stdb: SpacetimeDB,
+    mut last_sync: Local<Duration>,
+) {
+    let now = time.elapsed();
+    if !requests.is_changed() && now.saturating_sub(*last_sync) < LOCK_SYNC_INTERVAL {
+        return;
+    }
+    *last_sync = now;
+
+    let Some(own_identity) = stdb.try_identity() else {
+        return;
+    };
+
+    let wanted: HashSet<u64> = requests
+        .drag
+        .iter()
+        .chain(&requests.details)
+        .copied()
+        .collect();
+    let held: HashMap<u64, Timestamp> = stdb
+        .db()
+        .object_lock()
+        .iter()
+        .filter(|lock| lock.owner == own_identity)
+        .map(|lock| (lock.object_id, lock.expires_at))
+        .collect();
+
+    let timestamp = Timestamp::now();
+    let acquire: Vec<u64> = wanted
+        .iter()
+        .copied()
+        .filter(|id| match held.get(id) {
+            Some(expires_at) => expires_at
+                .duration_since(timestamp)
+                .is_none_or(|left| left < LOCK_RENEW_BEFORE),
+            None => locked_by_other(&stdb, *id).is_none(),
+        })
+        .collect();
+    let release: Vec<u64> = held
+        .keys()
+        .copied()
+        .filter(|id| !wanted.contains(id))
+        .collect();
+
+    if !acquire.is_empty()
+        && let Err(err) = stdb.reducers().acquire_locks(acquire)
+    {
+        eprintln!("Failed to acquire object locks: {err}");
+    }
+    if !release.is_empty()
+        && let Err(err) = stdb.reducers().release_locks(release)
+    {
+        eprintln!("Failed to release object locks: {err}");
+    }
+}
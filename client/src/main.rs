@@ -1,10 +1,19 @@
+mod colliders;
 mod config;
+mod convex_hull;
 mod flycam;
 mod gizmo;
 mod infinite_grid;
+mod locks;
 mod module_bindings;
+mod pending_ops;
+mod presence;
+mod selection;
+mod snapshot;
 mod spacetimedb;
+mod transform_preview;
 mod ui;
+mod undo;
 mod world_object;
 
 use bevy::asset::AssetPlugin;
@@ -32,10 +41,22 @@ fn main() {
         spacetimedb::plugin,
         world_object::plugin,
         default_plugins,
+        pending_ops::plugin,
+        undo::plugin,
+        locks::plugin,
+        transform_preview::plugin,
+        presence::plugin,
+        selection::plugin,
+        snapshot::plugin,
         gizmo::plugin,
         MeshPickingPlugin,
         ui::plugin,
-        infinite_grid::plugin,
+        // Nested: plugin tuples hold at most 15 entries.
+        (
+            infinite_grid::plugin,
+            colliders::plugin,
+            convex_hull::plugin,
+        ),
         flycam::plugin,
     ));
     app.add_systems(Startup, setup_sun);
@@ -6,44 +6,130 @@
 #![allow(unused, clippy::all)]
 use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 
+pub mod acquire_locks_reducer;
+pub mod append_world_import_chunk_reducer;
 pub mod asset_kind_type;
 pub mod ball_type;
+pub mod begin_world_import_reducer;
+pub mod cancel_world_import_reducer;
 pub mod capsule_type;
+pub mod clear_transform_previews_reducer;
 pub mod collision_shape_type;
+pub mod commit_world_import_reducer;
 pub mod convex_hull_type;
 pub mod cuboid_type;
+pub mod delete_object_reducer;
+pub mod delete_objects_reducer;
+pub mod edit_log_table;
+pub mod edit_log_type;
+pub mod editor_identity_table;
+pub mod editor_identity_type;
+pub mod editor_presence_table;
+pub mod editor_presence_type;
+pub mod grant_editor_reducer;
 pub mod heightfield_type;
 pub mod insert_object_reducer;
 pub mod move_object_reducer;
+pub mod object_lock_table;
+pub mod object_lock_type;
+pub mod preview_transform_type;
+pub mod preview_transforms_reducer;
 pub mod primitive_shape_type;
+pub mod prune_edit_log_reducer;
 pub mod quat_type;
+pub mod release_locks_reducer;
+pub mod restore_object_reducer;
+pub mod revoke_editor_reducer;
 pub mod rotate_object_reducer;
 pub mod scale_object_reducer;
 pub mod segment_type;
+pub mod set_collision_shape_reducer;
+pub mod transform_preview_table;
+pub mod transform_preview_type;
 pub mod triangle_type;
+pub mod update_presence_reducer;
 pub mod vec_3_type;
 pub mod world_object_table;
 pub mod world_object_type;
 
+pub use acquire_locks_reducer::{
+    acquire_locks, set_flags_for_acquire_locks, AcquireLocksCallbackId,
+};
+pub use append_world_import_chunk_reducer::{
+    append_world_import_chunk, set_flags_for_append_world_import_chunk,
+    AppendWorldImportChunkCallbackId,
+};
 pub use asset_kind_type::AssetKind;
 pub use ball_type::Ball;
+pub use begin_world_import_reducer::{
+    begin_world_import, set_flags_for_begin_world_import, BeginWorldImportCallbackId,
+};
+pub use cancel_world_import_reducer::{
+    cancel_world_import, set_flags_for_cancel_world_import, CancelWorldImportCallbackId,
+};
 pub use capsule_type::Capsule;
+pub use clear_transform_previews_reducer::{
+    clear_transform_previews, set_flags_for_clear_transform_previews,
+    ClearTransformPreviewsCallbackId,
+};
 pub use collision_shape_type::CollisionShape;
+pub use commit_world_import_reducer::{
+    commit_world_import, set_flags_for_commit_world_import, CommitWorldImportCallbackId,
+};
 pub use convex_hull_type::ConvexHull;
 pub use cuboid_type::Cuboid;
+pub use delete_object_reducer::{
+    delete_object, set_flags_for_delete_object, DeleteObjectCallbackId,
+};
+pub use delete_objects_reducer::{
+    delete_objects, set_flags_for_delete_objects, DeleteObjectsCallbackId,
+};
+pub use edit_log_table::*;
+pub use edit_log_type::EditLog;
+pub use editor_identity_table::*;
+pub use editor_identity_type::EditorIdentity;
+pub use editor_presence_table::*;
+pub use editor_presence_type::EditorPresence;
+pub use grant_editor_reducer::{grant_editor, set_flags_for_grant_editor, GrantEditorCallbackId};
 pub use heightfield_type::Heightfield;
 pub use insert_object_reducer::{
     insert_object, set_flags_for_insert_object, InsertObjectCallbackId,
 };
 pub use move_object_reducer::{move_object, set_flags_for_move_object, MoveObjectCallbackId};
+pub use object_lock_table::*;
+pub use object_lock_type::ObjectLock;
+pub use preview_transform_type::PreviewTransform;
+pub use preview_transforms_reducer::{
+    preview_transforms, set_flags_for_preview_transforms, PreviewTransformsCallbackId,
+};
 pub use primitive_shape_type::PrimitiveShape;
+pub use prune_edit_log_reducer::{
+    prune_edit_log, set_flags_for_prune_edit_log, PruneEditLogCallbackId,
+};
 pub use quat_type::Quat;
+pub use release_locks_reducer::{
+    release_locks, set_flags_for_release_locks, ReleaseLocksCallbackId,
+};
+pub use restore_object_reducer::{
+    restore_object, set_flags_for_restore_object, RestoreObjectCallbackId,
+};
+pub use revoke_editor_reducer::{
+    revoke_editor, set_flags_for_revoke_editor, RevokeEditorCallbackId,
+};
 pub use rotate_object_reducer::{
     rotate_object, set_flags_for_rotate_object, RotateObjectCallbackId,
 };
 pub use scale_object_reducer::{scale_object, set_flags_for_scale_object, ScaleObjectCallbackId};
 pub use segment_type::Segment;
+pub use set_collision_shape_reducer::{
+    set_collision_shape, set_flags_for_set_collision_shape, SetCollisionShapeCallbackId,
+};
+pub use transform_preview_table::*;
+pub use transform_preview_type::TransformPreview;
 pub use triangle_type::Triangle;
+pub use update_presence_reducer::{
+    set_flags_for_update_presence, update_presence, UpdatePresenceCallbackId,
+};
 pub use vec_3_type::Vec3;
 pub use world_object_table::*;
 pub use world_object_type::WorldObject;
@@ -56,10 +142,70 @@ pub use world_object_type::WorldObject;
 /// to indicate which reducer caused the event.
 
 pub enum Reducer {
-    InsertObject { object: WorldObject },
-    MoveObject { id: u64, translation: Vec3 },
-    RotateObject { id: u64, rotation: Quat },
-    ScaleObject { id: u64, scale: Vec3 },
+    AcquireLocks {
+        ids: Vec<u64>,
+    },
+    AppendWorldImportChunk {
+        chunk_index: u32,
+        objects: Vec<WorldObject>,
+    },
+    BeginWorldImport {
+        expected_rows: u64,
+    },
+    CancelWorldImport,
+    ClearTransformPreviews,
+    CommitWorldImport,
+    DeleteObject {
+        id: u64,
+    },
+    DeleteObjects {
+        ids: Vec<u64>,
+    },
+    GrantEditor {
+        identity: __sdk::Identity,
+        is_admin: bool,
+    },
+    InsertObject {
+        object: WorldObject,
+    },
+    MoveObject {
+        id: u64,
+        translation: Vec3,
+    },
+    PreviewTransforms {
+        previews: Vec<PreviewTransform>,
+    },
+    PruneEditLog {
+        older_than: __sdk::Timestamp,
+    },
+    ReleaseLocks {
+        ids: Vec<u64>,
+    },
+    RestoreObject {
+        object: WorldObject,
+    },
+    RevokeEditor {
+        identity: __sdk::Identity,
+    },
+    RotateObject {
+        id: u64,
+        rotation: Quat,
+    },
+    ScaleObject {
+        id: u64,
+        scale: Vec3,
+    },
+    SetCollisionShape {
+        id: u64,
+        collision_shape: CollisionShape,
+    },
+    UpdatePresence {
+        display_name: String,
+        color: u32,
+        camera_translation: Vec3,
+        camera_rotation: Quat,
+        selection: Vec<u64>,
+    },
 }
 
 impl __sdk::InModule for Reducer {
@@ -69,10 +215,26 @@ impl __sdk::InModule for Reducer {
 impl __sdk::Reducer for Reducer {
     fn reducer_name(&self) -> &'static str {
         match self {
+            Reducer::AcquireLocks { .. } => "acquire_locks",
+            Reducer::AppendWorldImportChunk { .. } => "append_world_import_chunk",
+            Reducer::BeginWorldImport { .. } => "begin_world_import",
+            Reducer::CancelWorldImport => "cancel_world_import",
+            Reducer::ClearTransformPreviews => "clear_transform_previews",
+            Reducer::CommitWorldImport => "commit_world_import",
+            Reducer::DeleteObject { .. } => "delete_object",
+            Reducer::DeleteObjects { .. } => "delete_objects",
+            Reducer::GrantEditor { .. } => "grant_editor",
             Reducer::InsertObject { .. } => "insert_object",
             Reducer::MoveObject { .. } => "move_object",
+            Reducer::PreviewTransforms { .. } => "preview_transforms",
+            Reducer::PruneEditLog { .. } => "prune_edit_log",
+            Reducer::ReleaseLocks { .. } => "release_locks",
+            Reducer::RestoreObject { .. } => "restore_object",
+            Reducer::RevokeEditor { .. } => "revoke_editor",
             Reducer::RotateObject { .. } => "rotate_object",
             Reducer::ScaleObject { .. } => "scale_object",
+            Reducer::SetCollisionShape { .. } => "set_collision_shape",
+            Reducer::UpdatePresence { .. } => "update_presence",
             _ => unreachable!(),
         }
     }
@@ -81,6 +243,49 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
     type Error = __sdk::Error;
     fn try_from(value: __ws::ReducerCallInfo<__ws::BsatnFormat>) -> __sdk::Result<Self> {
         match &value.reducer_name[..] {
+            "acquire_locks" => Ok(__sdk::parse_reducer_args::<
+                acquire_locks_reducer::AcquireLocksArgs,
+            >("acquire_locks", &value.args)?
+            .into()),
+            "append_world_import_chunk" => {
+                Ok(__sdk::parse_reducer_args::<
+                    append_world_import_chunk_reducer::AppendWorldImportChunkArgs,
+                >("append_world_import_chunk", &value.args)?
+                .into())
+            }
+            "begin_world_import" => Ok(__sdk::parse_reducer_args::<
+                begin_world_import_reducer::BeginWorldImportArgs,
+            >("begin_world_import", &value.args)?
+            .into()),
+            "cancel_world_import" => Ok(__sdk::parse_reducer_args::<
+                cancel_world_import_reducer::CancelWorldImportArgs,
+            >("cancel_world_import", &value.args)?
+            .into()),
+            "clear_transform_previews" => {
+                Ok(__sdk::parse_reducer_args::<
+                    clear_transform_previews_reducer::ClearTransformPreviewsArgs,
+                >("clear_transform_previews", &value.args)?
+                .into())
+            }
+            "commit_world_import" => Ok(__sdk::parse_reducer_args::<
+                commit_world_import_reducer::CommitWorldImportArgs,
+            >("commit_world_import", &value.args)?
+            .into()),
+            "delete_object" => Ok(__sdk::parse_reducer_args::<
+                delete_object_reducer::DeleteObjectArgs,
+            >("delete_object", &value.args)?
+            .into()),
+            "delete_objects" => Ok(__sdk::parse_reducer_args::<
+                delete_objects_reducer::DeleteObjectsArgs,
+            >("delete_objects", &value.args)?
+            .into()),
+            "grant_editor" => Ok(
+                __sdk::parse_reducer_args::<grant_editor_reducer::GrantEditorArgs>(
+                    "grant_editor",
+                    &value.args,
+                )?
+                .into(),
+            ),
             "insert_object" => Ok(__sdk::parse_reducer_args::<
                 insert_object_reducer::InsertObjectArgs,
             >("insert_object", &value.args)?
@@ -92,6 +297,26 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
                 )?
                 .into(),
             ),
+            "preview_transforms" => Ok(__sdk::parse_reducer_args::<
+                preview_transforms_reducer::PreviewTransformsArgs,
+            >("preview_transforms", &value.args)?
+            .into()),
+            "prune_edit_log" => Ok(__sdk::parse_reducer_args::<
+                prune_edit_log_reducer::PruneEditLogArgs,
+            >("prune_edit_log", &value.args)?
+            .into()),
+            "release_locks" => Ok(__sdk::parse_reducer_args::<
+                release_locks_reducer::ReleaseLocksArgs,
+            >("release_locks", &value.args)?
+            .into()),
+            "restore_object" => Ok(__sdk::parse_reducer_args::<
+                restore_object_reducer::RestoreObjectArgs,
+            >("restore_object", &value.args)?
+            .into()),
+            "revoke_editor" => Ok(__sdk::parse_reducer_args::<
+                revoke_editor_reducer::RevokeEditorArgs,
+            >("revoke_editor", &value.args)?
+            .into()),
             "rotate_object" => Ok(__sdk::parse_reducer_args::<
                 rotate_object_reducer::RotateObjectArgs,
             >("rotate_object", &value.args)?
@@ -103,6 +328,14 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
                 )?
                 .into(),
             ),
+            "set_collision_shape" => Ok(__sdk::parse_reducer_args::<
+                set_collision_shape_reducer::SetCollisionShapeArgs,
+            >("set_collision_shape", &value.args)?
+            .into()),
+            "update_presence" => Ok(__sdk::parse_reducer_args::<
+                update_presence_reducer::UpdatePresenceArgs,
+            >("update_presence", &value.args)?
+            .into()),
             unknown => {
                 Err(
                     __sdk::InternalError::unknown_name("reducer", unknown, "ReducerCallInfo")
@@ -117,6 +350,11 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
 #[allow(non_snake_case)]
 #[doc(hidden)]
 pub struct DbUpdate {
+    edit_log: __sdk::TableUpdate<EditLog>,
+    editor_identity: __sdk::TableUpdate<EditorIdentity>,
+    editor_presence: __sdk::TableUpdate<EditorPresence>,
+    object_lock: __sdk::TableUpdate<ObjectLock>,
+    transform_preview: __sdk::TableUpdate<TransformPreview>,
     world_object: __sdk::TableUpdate<WorldObject>,
 }
 
@@ -126,6 +364,21 @@ impl TryFrom<__ws::DatabaseUpdate<__ws::BsatnFormat>> for DbUpdate {
         let mut db_update = DbUpdate::default();
         for table_update in raw.tables {
             match &table_update.table_name[..] {
+                "edit_log" => db_update
+                    .edit_log
+                    .append(edit_log_table::parse_table_update(table_update)?),
+                "editor_identity" => db_update
+                    .editor_identity
+                    .append(editor_identity_table::parse_table_update(table_update)?),
+                "editor_presence" => db_update
+                    .editor_presence
+                    .append(editor_presence_table::parse_table_update(table_update)?),
+                "object_lock" => db_update
+                    .object_lock
+                    .append(object_lock_table::parse_table_update(table_update)?),
+                "transform_preview" => db_update
+                    .transform_preview
+                    .append(transform_preview_table::parse_table_update(table_update)?),
                 "world_object" => db_update
                     .world_object
                     .append(world_object_table::parse_table_update(table_update)?),
@@ -155,6 +408,21 @@ impl __sdk::DbUpdate for DbUpdate {
     ) -> AppliedDiff<'_> {
         let mut diff = AppliedDiff::default();
 
+        diff.edit_log = cache
+            .apply_diff_to_table::<EditLog>("edit_log", &self.edit_log)
+            .with_updates_by_pk(|row| &row.id);
+        diff.editor_identity = cache
+            .apply_diff_to_table::<EditorIdentity>("editor_identity", &self.editor_identity)
+            .with_updates_by_pk(|row| &row.identity);
+        diff.editor_presence = cache
+            .apply_diff_to_table::<EditorPresence>("editor_presence", &self.editor_presence)
+            .with_updates_by_pk(|row| &row.identity);
+        diff.object_lock = cache
+            .apply_diff_to_table::<ObjectLock>("object_lock", &self.object_lock)
+            .with_updates_by_pk(|row| &row.object_id);
+        diff.transform_preview = cache
+            .apply_diff_to_table::<TransformPreview>("transform_preview", &self.transform_preview)
+            .with_updates_by_pk(|row| &row.object_id);
         diff.world_object = cache
             .apply_diff_to_table::<WorldObject>("world_object", &self.world_object)
             .with_updates_by_pk(|row| &row.id);
@@ -167,6 +435,11 @@ impl __sdk::DbUpdate for DbUpdate {
 #[allow(non_snake_case)]
 #[doc(hidden)]
 pub struct AppliedDiff<'r> {
+    edit_log: __sdk::TableAppliedDiff<'r, EditLog>,
+    editor_identity: __sdk::TableAppliedDiff<'r, EditorIdentity>,
+    editor_presence: __sdk::TableAppliedDiff<'r, EditorPresence>,
+    object_lock: __sdk::TableAppliedDiff<'r, ObjectLock>,
+    transform_preview: __sdk::TableAppliedDiff<'r, TransformPreview>,
     world_object: __sdk::TableAppliedDiff<'r, WorldObject>,
     __unused: std::marker::PhantomData<&'r ()>,
 }
@@ -181,6 +454,23 @@ impl<'r> __sdk::AppliedDiff<'r> for AppliedDiff<'r> {
         event: &EventContext,
         callbacks: &mut __sdk::DbCallbacks<RemoteModule>,
     ) {
+        callbacks.invoke_table_row_callbacks::<EditLog>("edit_log", &self.edit_log, event);
+        callbacks.invoke_table_row_callbacks::<EditorIdentity>(
+            "editor_identity",
+            &self.editor_identity,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<EditorPresence>(
+            "editor_presence",
+            &self.editor_presence,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<ObjectLock>("object_lock", &self.object_lock, event);
+        callbacks.invoke_table_row_callbacks::<TransformPreview>(
+            "transform_preview",
+            &self.transform_preview,
+            event,
+        );
         callbacks.invoke_table_row_callbacks::<WorldObject>(
             "world_object",
             &self.world_object,
@@ -905,6 +1195,11 @@ impl __sdk::SpacetimeModule for RemoteModule {
     type SubscriptionHandle = SubscriptionHandle;
 
     fn register_tables(client_cache: &mut __sdk::ClientCache<Self>) {
+        edit_log_table::register_table(client_cache);
+        editor_identity_table::register_table(client_cache);
+        editor_presence_table::register_table(client_cache);
+        object_lock_table::register_table(client_cache);
+        transform_preview_table::register_table(client_cache);
         world_object_table::register_table(client_cache);
     }
 }
@@ -0,0 +1,478 @@
+//! Commit/ack tracking for editor-initiated reducer calls (`PendingOps`).
+//!
+//! The editor never renders optimistically, so "I called `move_object`" and "the DB has the
+//! new translation" are different states. Every world edit goes through [`EditorOps`], which
+//! calls the reducer and records what the row should look like afterwards. An op resolves:
+//! - as committed once the subscription cache shows the expected row state (for inserts, whose
+//!   id the DB picks, once their `ReducerEvent` reports success), or
+//! - as failed when its `ReducerEvent` reports an error (or nothing confirms it in time).
+//!
+//! Resolutions are published as [`OpResolved`] messages so other systems (snapshot writing,
+//! UI status, undo) can react without knowing about reducers. Each op carries the rows it
+//! touched as they were when the call was made, and the frame it was issued in, so undo can
+//! group the calls one gesture made.
+
+use core::time::Duration;
+
+use bevy::{diagnostic::FrameCount, ecs::system::SystemParam, prelude::*};
+use bevy_spacetimedb::ReadReducerMessage;
+use spacetimedb_sdk::{ReducerEvent, Status};
+
+use crate::{
+    module_bindings::{
+        AssetKind, CollisionShape, Quat as DbQuat, Reducer, Vec3 as DbVec3, WorldObject,
+        WorldObjectTableAccess, delete_object, delete_objects, insert_object, move_object,
+        restore_object, rotate_object, scale_object, set_collision_shape,
+    },
+    snapshot::SyncState,
+    spacetimedb::{
+        DeleteObject, DeleteObjects, InsertObject, MoveObject, RestoreObject, RotateObject,
+        ScaleObject, SetCollisionShape, SpacetimeDB,
+    },
+};
+
+/// How long an op may stay unconfirmed before it's reported as failed.
+const PENDING_OP_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Tolerance when comparing floats we sent with floats the DB stored.
+const FLOAT_EPSILON: f32 = 1e-5;
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<PendingOps>();
+    app.add_message::<OpResolved>();
+    app.add_systems(
+        Update,
+        (
+            resolve_from_reducer_events,
+            resolve_from_cache,
+            expire_stale_ops,
+        )
+            .chain(),
+    );
+}
+
+/// The row state an op expects to observe in the subscription cache once it commits.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expectation {
+    /// A row equal to `object` apart from the id, which the DB assigns. Only the reducer event
+    /// tells which row that is, so the cache never confirms it.
+    Inserted {
+        object: WorldObject,
+    },
+    Translation {
+        id: u64,
+        translation: DbVec3,
+    },
+    Rotation {
+        id: u64,
+        rotation: DbQuat,
+    },
+    Scale {
+        id: u64,
+        scale: DbVec3,
+    },
+    Collision {
+        id: u64,
+        shape: CollisionShape,
+    },
+    /// None of these rows exist anymore.
+    Deleted {
+        ids: Vec<u64>,
+    },
+    /// A row equal to `object`, id included, exists.
+    Restored {
+        object: WorldObject,
+    },
+}
+
+impl Expectation {
+    /// Whether the subscription cache currently satisfies this expectation.
+    fn is_met(&self, stdb: &SpacetimeDB) -> bool {
+        let table = stdb.db().world_object();
+        match self {
+            Expectation::Inserted { .. } => false,
+            Expectation::Translation { id, translation } => table
+                .id()
+                .find(id)
+                .is_some_and(|row| vec3_approx_eq(&row.translation, translation)),
+            Expectation::Rotation { id, rotation } => table
+                .id()
+                .find(id)
+                .is_some_and(|row| quat_approx_eq(&row.rotation, rotation)),
+            Expectation::Scale { id, scale } => table
+                .id()
+                .find(id)
+                .is_some_and(|row| vec3_approx_eq(&row.scale, scale)),
+            Expectation::Collision { id, shape } => table
+                .id()
+                .find(id)
+                .is_some_and(|row| row.collision_shape == *shape),
+            Expectation::Deleted { ids } => ids.iter().all(|id| table.id().find(id).is_none()),
+            Expectation::Restored { object } => table
+                .id()
+                .find(&object.id)
+                .is_some_and(|row| rows_match(&row, object)),
+        }
+    }
+
+    /// Ids of the existing rows this op changes.
+    fn touched_ids(&self) -> &[u64] {
+        match self {
+            Expectation::Translation { id, .. }
+            | Expectation::Rotation { id, .. }
+            | Expectation::Scale { id, .. }
+            | Expectation::Collision { id, .. } => std::slice::from_ref(id),
+            Expectation::Deleted { ids } => ids,
+            Expectation::Inserted { .. } | Expectation::Restored { .. } => &[],
+        }
+    }
+}
+
+/// `object` as the DB stores it: reducers normalize asset paths to forward slashes.
+fn as_stored(mut object: WorldObject) -> WorldObject {
+    if let AssetKind::Path(path) = &object.asset {
+        object.asset = AssetKind::Path(path.replace('\\', "/"));
+    }
+    object
+}
+
+/// Whether two rows are the same object in the same state, allowing for float round-off.
+pub fn rows_match(a: &WorldObject, b: &WorldObject) -> bool {
+    a.id == b.id && contents_match(a, b)
+}
+
+/// Whether two rows hold the same state, ignoring their ids.
+pub fn contents_match(a: &WorldObject, b: &WorldObject) -> bool {
+    a.asset == b.asset
+        && vec3_approx_eq(&a.translation, &b.translation)
+        && quat_approx_eq(&a.rotation, &b.rotation)
+        && vec3_approx_eq(&a.scale, &b.scale)
+        && a.collision_shape == b.collision_shape
+}
+
+fn vec3_approx_eq(a: &DbVec3, b: &DbVec3) -> bool {
+    (a.x - b.x).abs() <= FLOAT_EPSILON
+        && (a.y - b.y).abs() <= FLOAT_EPSILON
+        && (a.z - b.z).abs() <= FLOAT_EPSILON
+}
+
+fn quat_approx_eq(a: &DbQuat, b: &DbQuat) -> bool {
+    // q and -q are the same rotation.
+    let dot = a.x * b.x + a.y * b.y + a.z * b.z + a.w * b.w;
+    (dot.abs() - 1.0).abs() <= FLOAT_EPSILON * 10.0
+}
+
+/// Why an op was issued.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OpOrigin {
+    /// A regular edit, which becomes a new undo step.
+    #[default]
+    Edit,
+    /// Undoing or redoing an existing step.
+    Undo,
+    Redo,
+}
+
+/// A reducer call the editor made that hasn't been confirmed or rejected yet.
+#[derive(Debug, Clone)]
+pub struct PendingOp {
+    /// The reducer call exactly as sent, used to match its `ReducerEvent`.
+    pub call: Reducer,
+    pub expectation: Expectation,
+    /// The existing rows the call changes, as they were when it was made.
+    pub before: Vec<WorldObject>,
+    pub origin: OpOrigin,
+    /// `FrameCount` when the call was made. Calls from the same frame belong to one gesture.
+    pub frame: u32,
+    /// `Time::elapsed` when the call was made.
+    pub issued_at: Duration,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum OpOutcome {
+    Committed,
+    Failed(String),
+}
+
+/// Sent once per op when it leaves `PendingOps`.
+#[derive(Message, Debug, Clone)]
+pub struct OpResolved {
+    pub op: PendingOp,
+    pub outcome: OpOutcome,
+}
+
+/// Editor reducer calls that are still waiting for the DB, oldest first.
+#[derive(Resource, Default, Debug)]
+pub struct PendingOps {
+    ops: Vec<PendingOp>,
+}
+
+impl PendingOps {
+    pub fn is_empty(&self) -> bool {
+        self.ops.is_empty()
+    }
+
+    fn push(&mut self, op: PendingOp) {
+        self.ops.push(op);
+    }
+}
+
+/// Issues world-editing reducer calls and records each one in [`PendingOps`].
+///
+/// Use this instead of `stdb.reducers()` for anything that changes `world_object`, otherwise
+/// the change is never confirmed and the snapshot won't pick it up. Calls are refused while
+/// the world is out of sync with the snapshot (see [`SyncState`]).
+#[derive(SystemParam)]
+pub struct EditorOps<'w, 's> {
+    stdb: SpacetimeDB<'w>,
+    pending: ResMut<'w, PendingOps>,
+    sync: Res<'w, SyncState>,
+    time: Res<'w, Time>,
+    frame: Res<'w, FrameCount>,
+    /// Origin recorded for calls made through this param; only `replaying` changes it.
+    origin: Local<'s, OpOrigin>,
+}
+
+impl EditorOps<'_, '_> {
+    /// Make the calls in `issue` as part of undoing or redoing, so they aren't recorded as new edits.
+    pub fn replaying(&mut self, origin: OpOrigin, issue: impl FnOnce(&mut Self)) {
+        *self.origin = origin;
+        issue(self);
+        *self.origin = OpOrigin::Edit;
+    }
+
+    /// Record `call` and send it with `send`. If sending fails there's nothing to wait for, so no op is recorded.
+    fn track(
+        &mut self,
+        call: Reducer,
+        expectation: Expectation,
+        send: impl FnOnce(&SpacetimeDB) -> spacetimedb_sdk::Result<()>,
+    ) {
+        if !self.sync.allows_edits() {
+            eprintln!("World edits are blocked until the world is in sync: {call:?}");
+            return;
+        }
+
+        let table = self.stdb.db().world_object();
+        let before = expectation
+            .touched_ids()
+            .iter()
+            .filter_map(|id| table.id().find(id))
+            .collect();
+
+        if let Err(err) = send(&self.stdb) {
+            eprintln!("Failed to send reducer call {call:?}: {err}");
+            return;
+        }
+        self.pending.push(PendingOp {
+            call,
+            expectation,
+            before,
+            origin: *self.origin,
+            frame: self.frame.0,
+            issued_at: self.time.elapsed(),
+        });
+    }
+
+    pub fn insert_object(&mut self, object: WorldObject) {
+        self.track(
+            Reducer::InsertObject {
+                object: object.clone(),
+            },
+            Expectation::Inserted {
+                object: as_stored(object.clone()),
+            },
+            |stdb| stdb.reducers().insert_object(object),
+        )
+    }
+
+    pub fn move_object(&mut self, id: u64, translation: DbVec3) {
+        self.track(
+            Reducer::MoveObject {
+                id,
+                translation: translation.clone(),
+            },
+            Expectation::Translation {
+                id,
+                translation: translation.clone(),
+            },
+            |stdb| stdb.reducers().move_object(id, translation),
+        )
+    }
+
+    pub fn rotate_object(&mut self, id: u64, rotation: DbQuat) {
+        self.track(
+            Reducer::RotateObject {
+                id,
+                rotation: rotation.clone(),
+            },
+            Expectation::Rotation {
+                id,
+                rotation: rotation.clone(),
+            },
+            |stdb| stdb.reducers().rotate_object(id, rotation),
+        )
+    }
+
+    pub fn scale_object(&mut self, id: u64, scale: DbVec3) {
+        self.track(
+            Reducer::ScaleObject {
+                id,
+                scale: scale.clone(),
+            },
+            Expectation::Scale {
+                id,
+                scale: scale.clone(),
+            },
+            |stdb| stdb.reducers().scale_object(id, scale),
+        )
+    }
+
+    pub fn set_collision_shape(&mut self, id: u64, shape: CollisionShape) {
+        self.track(
+            Reducer::SetCollisionShape {
+                id,
+                collision_shape: shape.clone(),
+            },
+            Expectation::Collision {
+                id,
+                shape: shape.clone(),
+            },
+            |stdb| stdb.reducers().set_collision_shape(id, shape),
+        )
+    }
+
+    pub fn restore_object(&mut self, object: WorldObject) {
+        self.track(
+            Reducer::RestoreObject {
+                object: object.clone(),
+            },
+            Expectation::Restored {
+                object: as_stored(object.clone()),
+            },
+            |stdb| stdb.reducers().restore_object(object),
+        )
+    }
+
+    pub fn delete_object(&mut self, id: u64) {
+        self.track(
+            Reducer::DeleteObject { id },
+            Expectation::Deleted { ids: vec![id] },
+            |stdb| stdb.reducers().delete_object(id),
+        )
+    }
+
+    pub fn delete_objects(&mut self, ids: Vec<u64>) {
+        self.track(
+            Reducer::DeleteObjects { ids: ids.clone() },
+            Expectation::Deleted { ids: ids.clone() },
+            |stdb| stdb.reducers().delete_objects(ids),
+        )
+    }
+}
+
+/// Fail ops whose reducer reported an error, and commit inserts whose reducer succeeded.
+/// Matching is by the exact call we sent.
+#[allow(clippy::too_many_arguments)]
+fn resolve_from_reducer_events(
+    stdb: SpacetimeDB,
+    mut pending: ResMut<PendingOps>,
+    mut resolved: MessageWriter<OpResolved>,
+    mut inserts: ReadReducerMessage<InsertObject>,
+    mut moves: ReadReducerMessage<MoveObject>,
+    mut rotates: ReadReducerMessage<RotateObject>,
+    mut scales: ReadReducerMessage<ScaleObject>,
+    mut collisions: ReadReducerMessage<SetCollisionShape>,
+    mut deletes: ReadReducerMessage<DeleteObject>,
+    mut batch_deletes: ReadReducerMessage<DeleteObjects>,
+    mut restores: ReadReducerMessage<RestoreObject>,
+) {
+    let events: Vec<&ReducerEvent<Reducer>> = inserts
+        .read()
+        .map(|m| &m.result.event)
+        .chain(moves.read().map(|m| &m.result.event))
+        .chain(rotates.read().map(|m| &m.result.event))
+        .chain(scales.read().map(|m| &m.result.event))
+        .chain(collisions.read().map(|m| &m.result.event))
+        .chain(deletes.read().map(|m| &m.result.event))
+        .chain(batch_deletes.read().map(|m| &m.result.event))
+        .chain(restores.read().map(|m| &m.result.event))
+        .collect();
+
+    // Only our own calls can resolve our ops.
+    let our_identity = stdb.try_identity();
+
+    for event in events {
+        if Some(event.caller_identity) != our_identity {
+            continue;
+        }
+
+        let Some(index) = pending.ops.iter().position(|op| op.call == event.reducer) else {
+            continue;
+        };
+        let outcome = match &event.status {
+            // The cache confirms every other op; by the time the event arrives, it holds the row.
+            Status::Committed => {
+                if !matches!(pending.ops[index].expectation, Expectation::Inserted { .. }) {
+                    continue;
+                }
+                OpOutcome::Committed
+            }
+            Status::Failed(message) => OpOutcome::Failed(message.to_string()),
+            Status::OutOfEnergy => OpOutcome::Failed("Reducer ran out of energy".to_string()),
+        };
+
+        let op = pending.ops.remove(index);
+        if let OpOutcome::Failed(message) = &outcome {
+            eprintln!("Reducer call {:?} failed: {message}", op.call);
+        }
+        resolved.write(OpResolved { op, outcome });
+    }
+}
+
+/// Commit ops whose expected row state is visible in the subscription cache.
+fn resolve_from_cache(
+    stdb: SpacetimeDB,
+    mut pending: ResMut<PendingOps>,
+    mut resolved: MessageWriter<OpResolved>,
+) {
+    if pending.is_empty() {
+        return;
+    }
+
+    let (committed, still_pending): (Vec<_>, Vec<_>) = pending
+        .ops
+        .drain(..)
+        .partition(|op| op.expectation.is_met(&stdb));
+    pending.ops = still_pending;
+
+    for op in committed {
+        resolved.write(OpResolved {
+            op,
+            outcome: OpOutcome::Committed,
+        });
+    }
+}
+
+/// Fail ops nothing has confirmed for a while, e.g. because another editor changed the row again.
+fn expire_stale_ops(
+    time: Res<Time>,
+    mut pending: ResMut<PendingOps>,
+    mut resolved: MessageWriter<OpResolved>,
+) {
+    let now = time.elapsed();
+    let (expired, still_pending): (Vec<_>, Vec<_>) = pending
+        .ops
+        .drain(..)
+        .partition(|op| now.saturating_sub(op.issued_at) > PENDING_OP_TIMEOUT);
+    pending.ops = still_pending;
+
+    for op in expired {
+        resolved.write(OpResolved {
+            op,
+            outcome: OpOutcome::Failed(
+                "Timed out waiting for the database to confirm the change".to_string(),
+            ),
+        });
+    }
+}
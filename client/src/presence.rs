@@ -0,0 +1,254 @@
+//! Other editors in the viewport, through the `editor_presence` table.
+//!
+//! This editor publishes its display name, color, `FlyCam` transform and selection a few
+//! times per second (only when something changed). Every other connected editor is drawn as a
+//! camera frustum in its color with its name above it, and the objects it has selected get a
+//! bounding box outline in the same color.
+
+use core::time::Duration;
+
+use bevy::{camera::primitives::Aabb, prelude::*};
+use bevy_egui::{EguiContexts, EguiPrimaryContextPass, egui};
+use spacetimedb_sdk::{Identity, Table};
+
+use crate::{
+    config::ClientRuntimeConfig,
+    flycam::FlyCam,
+    module_bindings::{EditorPresence, EditorPresenceTableAccess, update_presence},
+    selection::Selection,
+    spacetimedb::SpacetimeDB,
+    world_object::WorldObjectIndex,
+};
+
+/// How often this editor's camera and selection are published, at most.
+const PRESENCE_RATE_HZ: f32 = 4.0;
+
+/// Distance from a remote camera to the far end of its drawn frustum.
+const FRUSTUM_DEPTH: f32 = 0.6;
+
+/// Field of view and aspect ratio assumed for remote cameras; the table doesn't carry them.
+const FRUSTUM_FOV: f32 = std::f32::consts::FRAC_PI_4;
+const FRUSTUM_ASPECT: f32 = 16.0 / 9.0;
+
+/// Outline size for selected objects whose meshes haven't loaded yet.
+const FALLBACK_OUTLINE_SIZE: f32 = 1.0;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(Update, publish_presence);
+    app.add_systems(
+        PostUpdate,
+        draw_remote_editors.after(TransformSystems::Propagate),
+    );
+    app.add_systems(EguiPrimaryContextPass, render_names);
+}
+
+/// What this editor published last.
+#[derive(Default)]
+struct PublishedPresence {
+    /// `Time::elapsed` of the last publish.
+    last_sent: Duration,
+    camera: Option<Transform>,
+    selection: Vec<u64>,
+}
+
+fn publish_presence(
+    time: Res<Time>,
+    config: Res<ClientRuntimeConfig>,
+    selection: Res<Selection>,
+    camera: Query<&GlobalTransform, With<FlyCam>>,
+    stdb: SpacetimeDB,
+    mut published: Local<PublishedPresence>,
+) {
+    let Some(identity) = stdb.try_identity() else {
+        return;
+    };
+
+    let now = time.elapsed();
+    if now.saturating_sub(published.last_sent) < Duration::from_secs_f32(1.0 / PRESENCE_RATE_HZ) {
+        return;
+    }
+
+    let Ok(camera) = camera.single() else {
+        return;
+    };
+    let camera = camera.compute_transform();
+    if published.camera == Some(camera) && published.selection == selection.ids() {
+        return;
+    }
+
+    let display_name = config
+        .display_name
+        .clone()
+        .unwrap_or_else(|| identity.to_abbreviated_hex().to_string());
+    let color = pack_color(identity_color(identity));
+
+    published.last_sent = now;
+    published.camera = Some(camera);
+    published.selection = selection.ids().to_vec();
+
+    // Identities without editor rights are refused; there's nothing to show them anyway.
+    if let Err(err) = stdb.reducers().update_presence(
+        display_name,
+        color,
+        camera.translation.into(),
+        camera.rotation.into(),
+        published.selection.clone(),
+    ) {
+        eprintln!("Failed to publish editor presence: {err}");
+    }
+}
+
+/// Every connected editor except this one.
+fn remote_editors(stdb: &SpacetimeDB) -> Vec<EditorPresence> {
+    let own_identity = stdb.try_identity();
+    stdb.db()
+        .editor_presence()
+        .iter()
+        .filter(|presence| Some(presence.identity) != own_identity)
+        .collect()
+}
+
+fn draw_remote_editors(
+    mut gizmos: Gizmos,
+    stdb: SpacetimeDB,
+    index: Res<WorldObjectIndex>,
+    objects: Query<&GlobalTransform>,
+    children: Query<&Children>,
+    bounds: Query<(&Aabb, &GlobalTransform)>,
+) {
+    for presence in remote_editors(&stdb) {
+        let color = unpack_color(presence.color);
+        let camera = Transform {
+            translation: presence.camera_translation.clone().into(),
+            rotation: presence.camera_rotation.clone().into(),
+            ..default()
+        };
+        draw_frustum(&mut gizmos, &camera, color);
+
+        for id in &presence.selection {
+            let Some(root) = index.get(*id) else {
+                continue;
+            };
+            let Ok(root_transform) = objects.get(root) else {
+                continue;
+            };
+
+            let outline = local_bounds(root, root_transform, &children, &bounds)
+                .unwrap_or(Transform::from_scale(Vec3::splat(FALLBACK_OUTLINE_SIZE)));
+            gizmos.cuboid(root_transform.mul_transform(outline), color);
+        }
+    }
+}
+
+/// A camera pyramid looking down `camera`'s forward, with a triangle marking its up side.
+fn draw_frustum(gizmos: &mut Gizmos, camera: &Transform, color: Color) {
+    let half_height = FRUSTUM_DEPTH * (FRUSTUM_FOV * 0.5).tan();
+    let half_width = half_height * FRUSTUM_ASPECT;
+
+    let corners = [
+        Vec3::new(-half_width, -half_height, -FRUSTUM_DEPTH),
+        Vec3::new(half_width, -half_height, -FRUSTUM_DEPTH),
+        Vec3::new(half_width, half_height, -FRUSTUM_DEPTH),
+        Vec3::new(-half_width, half_height, -FRUSTUM_DEPTH),
+    ]
+    .map(|corner| camera.transform_point(corner));
+
+    for corner in corners {
+        gizmos.line(camera.translation, corner, color);
+    }
+    gizmos.linestrip(corners.iter().copied().chain([corners[0]]), color);
+
+    let up = [
+        Vec3::new(-half_width * 0.5, half_height * 1.1, -FRUSTUM_DEPTH),
+        Vec3::new(0.0, half_height * 1.6, -FRUSTUM_DEPTH),
+        Vec3::new(half_width * 0.5, half_height * 1.1, -FRUSTUM_DEPTH),
+    ]
+    .map(|point| camera.transform_point(point));
+    gizmos.linestrip(up.iter().copied().chain([up[0]]), color);
+}
+
+/// The box around every mesh under `root`, in `root`'s local space, or `None` if nothing has
+/// bounds yet (e.g. a glTF scene that's still loading).
+fn local_bounds(
+    root: Entity,
+    root_transform: &GlobalTransform,
+    children: &Query<&Children>,
+    bounds: &Query<(&Aabb, &GlobalTransform)>,
+) -> Option<Transform> {
+    let to_local = root_transform.affine().inverse();
+
+    let mut min = Vec3::splat(f32::INFINITY);
+    let mut max = Vec3::splat(f32::NEG_INFINITY);
+    for (aabb, transform) in std::iter::once(root)
+        .chain(children.iter_descendants(root))
+        .filter_map(|entity| bounds.get(entity).ok())
+    {
+        let center = Vec3::from(aabb.center);
+        let half = Vec3::from(aabb.half_extents);
+        for corner in 0..8 {
+            let sign = Vec3::new(
+                if corner & 1 == 0 { -1.0 } else { 1.0 },
+                if corner & 2 == 0 { -1.0 } else { 1.0 },
+                if corner & 4 == 0 { -1.0 } else { 1.0 },
+            );
+            let point = to_local.transform_point3(transform.transform_point(center + half * sign));
+            min = min.min(point);
+            max = max.max(point);
+        }
+    }
+
+    (min.x <= max.x).then(|| Transform::from_translation((min + max) * 0.5).with_scale(max - min))
+}
+
+/// Each remote editor's name above its camera.
+fn render_names(
+    mut contexts: EguiContexts,
+    stdb: SpacetimeDB,
+    camera: Query<(&Camera, &GlobalTransform), With<FlyCam>>,
+) {
+    let Ok((cam, cam_gt)) = camera.single() else {
+        return;
+    };
+
+    let ctx = contexts.ctx_mut().expect("to get primary egui context");
+    // Panels and windows draw over the names.
+    let painter = ctx.layer_painter(egui::LayerId::background());
+
+    for presence in remote_editors(&stdb) {
+        let camera = Transform {
+            translation: presence.camera_translation.clone().into(),
+            rotation: presence.camera_rotation.clone().into(),
+            ..default()
+        };
+        let above = camera.translation + camera.up() * FRUSTUM_DEPTH * 0.5;
+        let Ok(point) = cam.world_to_viewport(cam_gt, above) else {
+            continue;
+        };
+
+        let [r, g, b, _] = unpack_color(presence.color).to_srgba().to_u8_array();
+        painter.text(
+            egui::pos2(point.x, point.y),
+            egui::Align2::CENTER_BOTTOM,
+            &presence.display_name,
+            egui::FontId::proportional(13.0),
+            egui::Color32::from_rgb(r, g, b),
+        );
+    }
+}
+
+/// A stable, distinct-enough color per identity.
+fn identity_color(identity: Identity) -> Color {
+    let hash = identity.to_byte_array().iter().fold(0u32, |hash, byte| {
+        hash.wrapping_mul(31).wrapping_add(u32::from(*byte))
+    });
+    Color::hsl((hash % 360) as f32, 0.8, 0.6)
+}
+
+fn pack_color(color: Color) -> u32 {
+    let [r, g, b, _] = color.to_srgba().to_u8_array();
+    u32::from(r) << 16 | u32::from(g) << 8 | u32::from(b)
+}
+
+fn unpack_color(color: u32) -> Color {
+    Color::srgb_u8((color >> 16) as u8, (color >> 8) as u8, color as u8)
+}
@@ -0,0 +1,199 @@
+//! Marquee (box) selection: LMB-drag on empty space selects every object inside the rectangle.
+//!
+//! Drags that start on empty space target the window entity, so object drags (transform tools)
+//! and the flycam's RMB/MMB handling never reach these observers. With Shift held the boxed
+//! objects are added to the selection, with Ctrl they are removed from it.
+
+use bevy::{camera::primitives::Aabb, prelude::*};
+use bevy_egui::{EguiContexts, EguiPrimaryContextPass, egui};
+
+use super::Selection;
+use crate::{
+    flycam::{FlyCam, FlyCamActive},
+    ui::transform_tools::TransformTool,
+    world_object::WorldObjectIndex,
+};
+
+const MARQUEE_FILL: egui::Color32 = egui::Color32::from_rgba_premultiplied(40, 90, 160, 40);
+const MARQUEE_STROKE: egui::Color32 = egui::Color32::from_rgb(90, 150, 230);
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<Marquee>();
+    app.add_observer(on_drag_start);
+    app.add_observer(on_drag);
+    app.add_observer(on_drag_end);
+    app.add_systems(EguiPrimaryContextPass, render);
+}
+
+/// How the boxed objects combine with the existing selection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MarqueeMode {
+    Replace,
+    Add,
+    Subtract,
+}
+
+impl MarqueeMode {
+    fn from_keys(keys: &ButtonInput<KeyCode>) -> Self {
+        if keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]) {
+            MarqueeMode::Subtract
+        } else if keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+            MarqueeMode::Add
+        } else {
+            MarqueeMode::Replace
+        }
+    }
+}
+
+/// The rectangle being dragged, in logical viewport pixels.
+#[derive(Resource, Default)]
+struct Marquee {
+    start: Option<Vec2>,
+    end: Vec2,
+}
+
+impl Marquee {
+    fn rect(&self) -> Option<Rect> {
+        self.start.map(|start| Rect::from_corners(start, self.end))
+    }
+}
+
+fn on_drag_start(
+    drag: On<Pointer<DragStart>>,
+    windows: Query<(), With<Window>>,
+    tool: Res<TransformTool>,
+    flycam_active: Res<FlyCamActive>,
+    mut contexts: EguiContexts,
+    mut marquee: ResMut<Marquee>,
+) {
+    if drag.button != PointerButton::Primary || windows.get(drag.entity).is_err() {
+        return;
+    }
+
+    if tool.is_active || flycam_active.0 {
+        return;
+    }
+
+    // Dragging a panel edge or a slider must not start a marquee behind it.
+    if let Ok(ctx) = contexts.ctx_mut()
+        && (ctx.is_pointer_over_area() || ctx.wants_pointer_input())
+    {
+        return;
+    }
+
+    marquee.start = Some(drag.pointer_location.position);
+    marquee.end = drag.pointer_location.position;
+}
+
+fn on_drag(drag: On<Pointer<Drag>>, mut marquee: ResMut<Marquee>) {
+    if drag.button != PointerButton::Primary || marquee.start.is_none() {
+        return;
+    }
+
+    marquee.end = drag.pointer_location.position;
+}
+
+#[allow(clippy::too_many_arguments)]
+fn on_drag_end(
+    drag: On<Pointer<DragEnd>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    flycam_active: Res<FlyCamActive>,
+    index: Res<WorldObjectIndex>,
+    children: Query<&Children>,
+    bounds: Query<(&Aabb, &GlobalTransform)>,
+    camera: Query<(&Camera, &GlobalTransform), With<FlyCam>>,
+    mut marquee: ResMut<Marquee>,
+    mut selection: ResMut<Selection>,
+) {
+    if drag.button != PointerButton::Primary {
+        return;
+    }
+
+    let Some(rect) = marquee.rect() else {
+        return;
+    };
+    *marquee = Marquee::default();
+
+    // RMB was pressed mid-drag and took over the camera; treat the marquee as cancelled.
+    if flycam_active.0 {
+        return;
+    }
+
+    let Ok((cam, cam_gt)) = camera.single() else {
+        return;
+    };
+
+    let boxed: Vec<u64> = index
+        .iter()
+        .filter(|(_, root)| {
+            std::iter::once(*root)
+                .chain(children.iter_descendants(*root))
+                .filter_map(|entity| bounds.get(entity).ok())
+                .filter_map(|(aabb, transform)| screen_bounds(cam, cam_gt, aabb, transform))
+                .any(|screen| !screen.intersect(rect).is_empty())
+        })
+        .map(|(id, _)| id)
+        .collect();
+
+    match MarqueeMode::from_keys(&keys) {
+        MarqueeMode::Replace => {
+            selection.clear();
+            boxed.into_iter().for_each(|id| selection.add(id));
+        }
+        MarqueeMode::Add => boxed.into_iter().for_each(|id| selection.add(id)),
+        MarqueeMode::Subtract => boxed.into_iter().for_each(|id| selection.remove(id)),
+    }
+}
+
+/// The screen-space rectangle covering a mesh's bounding box, or `None` if it's behind the camera.
+fn screen_bounds(
+    cam: &Camera,
+    cam_gt: &GlobalTransform,
+    aabb: &Aabb,
+    transform: &GlobalTransform,
+) -> Option<Rect> {
+    let center = Vec3::from(aabb.center);
+    let half = Vec3::from(aabb.half_extents);
+
+    let mut screen: Option<Rect> = None;
+    for corner in 0..8 {
+        let sign = Vec3::new(
+            if corner & 1 == 0 { -1.0 } else { 1.0 },
+            if corner & 2 == 0 { -1.0 } else { 1.0 },
+            if corner & 4 == 0 { -1.0 } else { 1.0 },
+        );
+        let world = transform.transform_point(center + half * sign);
+        let Ok(point) = cam.world_to_viewport(cam_gt, world) else {
+            continue;
+        };
+        screen = Some(match screen {
+            Some(rect) => rect.union_point(point),
+            None => Rect::from_corners(point, point),
+        });
+    }
+    screen
+}
+
+fn render(mut contexts: EguiContexts, marquee: Res<Marquee>) {
+    let Some(rect) = marquee.rect() else {
+        return;
+    };
+
+    let ctx = contexts.ctx_mut().expect("to get primary egui context");
+    let painter = ctx.layer_painter(egui::LayerId::new(
+        egui::Order::Foreground,
+        egui::Id::new("marquee_selection"),
+    ));
+
+    let rect = egui::Rect::from_min_max(
+        egui::pos2(rect.min.x, rect.min.y),
+        egui::pos2(rect.max.x, rect.max.y),
+    );
+    painter.rect_filled(rect, 0.0, MARQUEE_FILL);
+    painter.rect_stroke(
+        rect,
+        0.0,
+        egui::Stroke::new(1.0, MARQUEE_STROKE),
+        egui::StrokeKind::Inside,
+    );
+}
@@ -0,0 +1,300 @@
+//! Editor selection state.
+//!
+//! The selection is a list of `WorldObject` row ids (not entities), so it survives
+//! respawns caused by row updates and can be sent to reducers as-is.
+//!
+//! Clicking an object selects it; Shift adds to the selection, Ctrl toggles, and clicking
+//! empty space clears it. Selected objects are tinted, including every mesh a glTF scene
+//! spawns under them. Dragging on empty space draws a selection box (see `marquee`).
+
+mod marquee;
+
+use bevy::{
+    asset::AssetId,
+    platform::collections::{HashMap, HashSet},
+    prelude::*,
+};
+use bevy_egui::EguiContexts;
+use bevy_spacetimedb::ReadDeleteMessage;
+
+use crate::{
+    flycam::FlyCamActive,
+    module_bindings::WorldObject,
+    ui::transform_tools::TransformTool,
+    world_object::{ObjectId, WorldObjectIndex},
+};
+
+/// Emissive tint added to the materials of selected objects.
+const SELECTION_TINT: LinearRgba = LinearRgba::rgb(0.9, 0.45, 0.05);
+
+/// How far the pointer may travel between press and release for it to still count as a click.
+/// Picking sends `Click` after a drag too, which must not change the selection.
+const CLICK_MAX_TRAVEL_PX: f32 = 4.0;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_plugins(marquee::plugin);
+    app.init_resource::<Selection>();
+    app.init_resource::<TintedMaterials>();
+    app.init_resource::<TintRetry>();
+    app.init_resource::<PressPosition>();
+    app.add_observer(record_press_position);
+    app.add_observer(clear_on_empty_click);
+    app.add_systems(
+        Update,
+        (
+            prune_deleted,
+            update_tint.run_if(
+                resource_changed::<Selection>
+                    .or(any_match_filter::<Added<ObjectId>>)
+                    .or(any_match_filter::<Added<Children>>)
+                    .or(resource_equals(TintRetry(true))),
+            ),
+        )
+            .chain(),
+    );
+}
+
+/// The set of currently selected world objects, in the order they were selected.
+#[derive(Resource, Default, Debug, Clone, PartialEq, Eq)]
+pub struct Selection {
+    ids: Vec<u64>,
+    /// Same ids as `ids`, for fast lookups when thousands of objects are selected.
+    lookup: HashSet<u64>,
+}
+
+impl Selection {
+    pub fn ids(&self) -> &[u64] {
+        &self.ids
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    pub fn contains(&self, id: u64) -> bool {
+        self.lookup.contains(&id)
+    }
+
+    /// Replace the selection with a single object.
+    pub fn select_single(&mut self, id: u64) {
+        self.clear();
+        self.add(id);
+    }
+
+    /// Add an object to the selection if it isn't selected yet.
+    pub fn add(&mut self, id: u64) {
+        if self.lookup.insert(id) {
+            self.ids.push(id);
+        }
+    }
+
+    /// Select the object if it isn't selected, otherwise deselect it.
+    pub fn toggle(&mut self, id: u64) {
+        if self.contains(id) {
+            self.remove(id);
+        } else {
+            self.add(id);
+        }
+    }
+
+    pub fn remove(&mut self, id: u64) {
+        if self.lookup.remove(&id) {
+            self.ids.retain(|selected| *selected != id);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.ids.clear();
+        self.lookup.clear();
+    }
+}
+
+/// How a click combines with the existing selection, from the held modifier keys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectMode {
+    Replace,
+    Add,
+    Toggle,
+}
+
+impl SelectMode {
+    pub fn from_keys(keys: &ButtonInput<KeyCode>) -> Self {
+        if keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]) {
+            SelectMode::Toggle
+        } else if keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+            SelectMode::Add
+        } else {
+            SelectMode::Replace
+        }
+    }
+}
+
+/// Where the primary button was last pressed, to tell clicks apart from drags.
+#[derive(Resource, Default)]
+pub(crate) struct PressPosition(Option<Vec2>);
+
+impl PressPosition {
+    fn is_click(&self, release: Vec2) -> bool {
+        self.0
+            .is_some_and(|press| press.distance(release) <= CLICK_MAX_TRAVEL_PX)
+    }
+}
+
+fn record_press_position(press: On<Pointer<Press>>, mut position: ResMut<PressPosition>) {
+    if press.button == PointerButton::Primary {
+        position.0 = Some(press.pointer_location.position);
+    }
+}
+
+/// Observer attached to every world object entity: clicking it selects it.
+pub fn on_click(
+    click: On<Pointer<Click>>,
+    objects: Query<&ObjectId>,
+    keys: Res<ButtonInput<KeyCode>>,
+    press: Res<PressPosition>,
+    mut selection: ResMut<Selection>,
+) {
+    if click.button != PointerButton::Primary || !press.is_click(click.pointer_location.position) {
+        return;
+    }
+
+    let Ok(id) = objects.get(click.entity) else {
+        return;
+    };
+
+    match SelectMode::from_keys(&keys) {
+        SelectMode::Replace => selection.select_single(id.0),
+        SelectMode::Add => selection.add(id.0),
+        SelectMode::Toggle => selection.toggle(id.0),
+    }
+}
+
+/// Clicks that hit no object land on the window entity; a plain click there clears the selection.
+#[allow(clippy::too_many_arguments)]
+fn clear_on_empty_click(
+    click: On<Pointer<Click>>,
+    windows: Query<(), With<Window>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    tool: Res<TransformTool>,
+    flycam_active: Res<FlyCamActive>,
+    press: Res<PressPosition>,
+    mut contexts: EguiContexts,
+    mut selection: ResMut<Selection>,
+) {
+    if click.button != PointerButton::Primary || windows.get(click.entity).is_err() {
+        return;
+    }
+
+    if !press.is_click(click.pointer_location.position) {
+        return;
+    }
+
+    if tool.is_active || flycam_active.0 {
+        return;
+    }
+
+    // Clicking a panel or menu must not clear the selection.
+    if let Ok(ctx) = contexts.ctx_mut()
+        && (ctx.is_pointer_over_area() || ctx.wants_pointer_input())
+    {
+        return;
+    }
+
+    // A modifier means the user is building up a selection and just missed.
+    if SelectMode::from_keys(&keys) == SelectMode::Replace {
+        selection.clear();
+    }
+}
+
+/// Drop ids whose rows were deleted so reducers are never called with stale ids.
+fn prune_deleted(mut deleted: ReadDeleteMessage<WorldObject>, mut selection: ResMut<Selection>) {
+    for msg in deleted.read() {
+        selection.remove(msg.row.id);
+    }
+}
+
+/// Marks a mesh whose material was swapped for a tinted copy while its object is selected.
+#[derive(Component)]
+struct SelectionTint {
+    /// The object this mesh belongs to (itself or a glTF scene ancestor).
+    object: u64,
+    original: Handle<StandardMaterial>,
+}
+
+/// Tinted copy of each material that has been used by a selected mesh, keyed by the original.
+#[derive(Resource, Default)]
+struct TintedMaterials(HashMap<AssetId<StandardMaterial>, Handle<StandardMaterial>>);
+
+/// Set when a selected mesh's material hadn't loaded yet, so the tint is tried again next frame.
+#[derive(Resource, Default, PartialEq)]
+struct TintRetry(bool);
+
+/// Keep the tint in step with the selection.
+///
+/// Runs when the selection changes, and also when object entities or children spawn: glTF
+/// scenes spawn their meshes some time after the object entity, and row updates can respawn
+/// an object entirely.
+#[allow(clippy::too_many_arguments)]
+fn update_tint(
+    selection: Res<Selection>,
+    mut retry: ResMut<TintRetry>,
+    index: Res<WorldObjectIndex>,
+    children: Query<&Children>,
+    mut meshes: Query<(
+        &mut MeshMaterial3d<StandardMaterial>,
+        Option<&SelectionTint>,
+    )>,
+    tinted: Query<(Entity, &SelectionTint)>,
+    mut commands: Commands,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut tinted_materials: ResMut<TintedMaterials>,
+) {
+    retry.0 = false;
+
+    for (entity, tint) in &tinted {
+        if selection.contains(tint.object) {
+            continue;
+        }
+        if let Ok((mut material, _)) = meshes.get_mut(entity) {
+            material.0 = tint.original.clone();
+        }
+        commands.entity(entity).remove::<SelectionTint>();
+    }
+
+    for &id in selection.ids() {
+        let Some(root) = index.get(id) else {
+            continue;
+        };
+
+        for entity in std::iter::once(root).chain(children.iter_descendants(root)) {
+            let Ok((mut material, tint)) = meshes.get_mut(entity) else {
+                continue;
+            };
+            if tint.is_some() {
+                continue;
+            }
+
+            let original = material.0.clone();
+            let tinted = match tinted_materials.0.get(&original.id()) {
+                Some(tinted) => tinted.clone(),
+                None => {
+                    // Scene materials may still be loading; try again next frame.
+                    let Some(mut copy) = materials.get(&original).cloned() else {
+                        retry.0 = true;
+                        continue;
+                    };
+                    copy.emissive += SELECTION_TINT;
+                    let tinted = materials.add(copy);
+                    tinted_materials.0.insert(original.id(), tinted.clone());
+                    tinted
+                }
+            };
+
+            material.0 = tinted;
+            commands.entity(entity).insert(SelectionTint {
+                object: id,
+                original,
+            });
+        }
+    }
+}
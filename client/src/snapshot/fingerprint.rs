@@ -0,0 +1,175 @@
+//! Stable fingerprints of `world_object` rows, used to tell whether the snapshot and DB agree.
+//!
+//! The hash is 64-bit FNV-1a over a fixed byte encoding of every row in id order. Unlike
+//! `std::hash`, it doesn't depend on the Rust version or on the order rows arrived in, and
+//! floats are compared bit-for-bit (RON round-trips `f32` exactly).
+
+use std::fmt;
+
+use super::format::{
+    SnapshotAssetKind, SnapshotCollisionShape, SnapshotPrimitiveShape, SnapshotWorldObject,
+};
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fingerprint {
+    pub rows: usize,
+    pub hash: u64,
+}
+
+impl Fingerprint {
+    pub fn of<'a>(rows: impl IntoIterator<Item = &'a SnapshotWorldObject>) -> Self {
+        let mut rows: Vec<&SnapshotWorldObject> = rows.into_iter().collect();
+        rows.sort_by_key(|row| row.id);
+
+        let mut hasher = Fnv1a::default();
+        for row in &rows {
+            hasher.row(row);
+        }
+
+        Self {
+            rows: rows.len(),
+            hash: hasher.0,
+        }
+    }
+}
+
+impl fmt::Display for Fingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} object(s), hash {:016x}", self.rows, self.hash)
+    }
+}
+
+struct Fnv1a(u64);
+
+impl Default for Fnv1a {
+    fn default() -> Self {
+        Self(FNV_OFFSET_BASIS)
+    }
+}
+
+impl Fnv1a {
+    fn bytes(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    fn u8(&mut self, value: u8) {
+        self.bytes(&[value]);
+    }
+
+    fn u32(&mut self, value: u32) {
+        self.bytes(&value.to_le_bytes());
+    }
+
+    fn u64(&mut self, value: u64) {
+        self.bytes(&value.to_le_bytes());
+    }
+
+    fn f32(&mut self, value: f32) {
+        self.u32(value.to_bits());
+    }
+
+    fn f32s(&mut self, values: &[f32]) {
+        for value in values {
+            self.f32(*value);
+        }
+    }
+
+    /// Length-prefixed so `["ab", "c"]` and `["a", "bc"]` hash differently.
+    fn length(&mut self, len: usize) {
+        self.u64(len as u64);
+    }
+
+    fn row(&mut self, row: &SnapshotWorldObject) {
+        self.u64(row.id);
+        self.asset(&row.asset);
+        self.f32s(&row.translation);
+        self.f32s(&row.rotation);
+        self.f32s(&row.scale);
+        self.collision_shape(&row.collision_shape);
+    }
+
+    fn asset(&mut self, asset: &SnapshotAssetKind) {
+        match asset {
+            SnapshotAssetKind::Path(path) => {
+                self.u8(0);
+                self.length(path.len());
+                self.bytes(path.as_bytes());
+            }
+            SnapshotAssetKind::PrimitiveShape(shape) => {
+                self.u8(1);
+                self.primitive_shape(shape);
+            }
+        }
+    }
+
+    fn primitive_shape(&mut self, shape: &SnapshotPrimitiveShape) {
+        match shape {
+            SnapshotPrimitiveShape::Cuboid { half_extents } => {
+                self.u8(0);
+                self.f32s(half_extents);
+            }
+            SnapshotPrimitiveShape::Ball { radius } => {
+                self.u8(1);
+                self.f32(*radius);
+            }
+            SnapshotPrimitiveShape::Capsule { a, b, radius } => {
+                self.u8(2);
+                self.f32s(a);
+                self.f32s(b);
+                self.f32(*radius);
+            }
+        }
+    }
+
+    fn collision_shape(&mut self, shape: &SnapshotCollisionShape) {
+        match shape {
+            SnapshotCollisionShape::None => self.u8(0),
+            SnapshotCollisionShape::Cuboid { half_extents } => {
+                self.u8(1);
+                self.f32s(half_extents);
+            }
+            SnapshotCollisionShape::Ball { radius } => {
+                self.u8(2);
+                self.f32(*radius);
+            }
+            SnapshotCollisionShape::Capsule { a, b, radius } => {
+                self.u8(3);
+                self.f32s(a);
+                self.f32s(b);
+                self.f32(*radius);
+            }
+            SnapshotCollisionShape::Heightfield {
+                width,
+                height,
+                heights,
+                scale,
+            } => {
+                self.u8(4);
+                self.u32(*width);
+                self.u32(*height);
+                self.length(heights.len());
+                self.f32s(heights);
+                self.f32s(scale);
+            }
+            SnapshotCollisionShape::ConvexHull { points, indices } => {
+                self.u8(5);
+                self.length(points.len());
+                for point in points {
+                    self.f32s(point);
+                }
+                self.length(indices.len());
+                for [v_1, v_2, v_3] in indices {
+                    self.u32(*v_1);
+                    self.u32(*v_2);
+                    self.u32(*v_3);
+                }
+            }
+        }
+    }
+}
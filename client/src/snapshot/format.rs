@@ -0,0 +1,276 @@
+//! On-disk representation of the world snapshot.
+//!
+//! These mirror the generated `module_bindings` types, which only implement SpacetimeDB's own
+//! (binary) serialization. Keeping a separate serde model also means a regenerated binding can't
+//! silently change the snapshot file format; bump `SCHEMA_VERSION` when these types change.
+
+use serde::{Deserialize, Serialize};
+
+use crate::module_bindings as db;
+
+/// Version of the snapshot file format written by this editor build.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// Just enough of the file to check its version before parsing the rest.
+#[derive(Deserialize)]
+pub(super) struct SnapshotHeader {
+    pub schema_version: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct WorldSnapshot {
+    pub schema_version: u32,
+    /// Seconds since the Unix epoch when the snapshot was written.
+    pub saved_at: Option<u64>,
+    /// Every `world_object` row, ordered by id.
+    pub world_objects: Vec<SnapshotWorldObject>,
+}
+
+impl WorldSnapshot {
+    /// Build a snapshot from table rows. Rows are sorted by id so the file is stable.
+    pub fn from_rows(rows: impl IntoIterator<Item = db::WorldObject>) -> Self {
+        let mut world_objects: Vec<SnapshotWorldObject> =
+            rows.into_iter().map(Into::into).collect();
+        world_objects.sort_by_key(|row| row.id);
+
+        let saved_at = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .ok()
+            .map(|elapsed| elapsed.as_secs());
+
+        Self {
+            schema_version: SCHEMA_VERSION,
+            saved_at,
+            world_objects,
+        }
+    }
+
+    /// The snapshot's rows as table rows, e.g. to pass to a restore reducer.
+    pub fn to_rows(&self) -> Vec<db::WorldObject> {
+        self.world_objects.iter().cloned().map(Into::into).collect()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SnapshotWorldObject {
+    pub id: u64,
+    pub asset: SnapshotAssetKind,
+    pub translation: [f32; 3],
+    /// Quaternion as `[x, y, z, w]`.
+    pub rotation: [f32; 4],
+    pub scale: [f32; 3],
+    pub collision_shape: SnapshotCollisionShape,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum SnapshotAssetKind {
+    Path(String),
+    PrimitiveShape(SnapshotPrimitiveShape),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum SnapshotPrimitiveShape {
+    Cuboid {
+        half_extents: [f32; 3],
+    },
+    Ball {
+        radius: f32,
+    },
+    Capsule {
+        a: [f32; 3],
+        b: [f32; 3],
+        radius: f32,
+    },
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum SnapshotCollisionShape {
+    None,
+    Cuboid {
+        half_extents: [f32; 3],
+    },
+    Ball {
+        radius: f32,
+    },
+    Capsule {
+        a: [f32; 3],
+        b: [f32; 3],
+        radius: f32,
+    },
+    Heightfield {
+        width: u32,
+        height: u32,
+        heights: Vec<f32>,
+        scale: [f32; 3],
+    },
+    ConvexHull {
+        points: Vec<[f32; 3]>,
+        indices: Vec<[u32; 3]>,
+    },
+}
+
+fn vec3_to_array(v: db::Vec3) -> [f32; 3] {
+    [v.x, v.y, v.z]
+}
+
+fn array_to_vec3([x, y, z]: [f32; 3]) -> db::Vec3 {
+    db::Vec3 { x, y, z }
+}
+
+impl From<db::WorldObject> for SnapshotWorldObject {
+    fn from(row: db::WorldObject) -> Self {
+        Self {
+            id: row.id,
+            asset: row.asset.into(),
+            translation: vec3_to_array(row.translation),
+            rotation: [
+                row.rotation.x,
+                row.rotation.y,
+                row.rotation.z,
+                row.rotation.w,
+            ],
+            scale: vec3_to_array(row.scale),
+            collision_shape: row.collision_shape.into(),
+        }
+    }
+}
+
+impl From<SnapshotWorldObject> for db::WorldObject {
+    fn from(row: SnapshotWorldObject) -> Self {
+        let [x, y, z, w] = row.rotation;
+        Self {
+            id: row.id,
+            asset: row.asset.into(),
+            translation: array_to_vec3(row.translation),
+            rotation: db::Quat { x, y, z, w },
+            scale: array_to_vec3(row.scale),
+            collision_shape: row.collision_shape.into(),
+        }
+    }
+}
+
+impl From<db::AssetKind> for SnapshotAssetKind {
+    fn from(asset: db::AssetKind) -> Self {
+        match asset {
+            db::AssetKind::Path(path) => Self::Path(path),
+            db::AssetKind::PrimitiveShape(shape) => Self::PrimitiveShape(shape.into()),
+        }
+    }
+}
+
+impl From<SnapshotAssetKind> for db::AssetKind {
+    fn from(asset: SnapshotAssetKind) -> Self {
+        match asset {
+            SnapshotAssetKind::Path(path) => Self::Path(path),
+            SnapshotAssetKind::PrimitiveShape(shape) => Self::PrimitiveShape(shape.into()),
+        }
+    }
+}
+
+impl From<db::PrimitiveShape> for SnapshotPrimitiveShape {
+    fn from(shape: db::PrimitiveShape) -> Self {
+        match shape {
+            db::PrimitiveShape::Cuboid(cuboid) => Self::Cuboid {
+                half_extents: vec3_to_array(cuboid.half_extents),
+            },
+            db::PrimitiveShape::Ball(ball) => Self::Ball {
+                radius: ball.radius,
+            },
+            db::PrimitiveShape::Capsule(capsule) => Self::Capsule {
+                a: vec3_to_array(capsule.segment.a),
+                b: vec3_to_array(capsule.segment.b),
+                radius: capsule.radius,
+            },
+        }
+    }
+}
+
+impl From<SnapshotPrimitiveShape> for db::PrimitiveShape {
+    fn from(shape: SnapshotPrimitiveShape) -> Self {
+        match shape {
+            SnapshotPrimitiveShape::Cuboid { half_extents } => Self::Cuboid(db::Cuboid {
+                half_extents: array_to_vec3(half_extents),
+            }),
+            SnapshotPrimitiveShape::Ball { radius } => Self::Ball(db::Ball { radius }),
+            SnapshotPrimitiveShape::Capsule { a, b, radius } => Self::Capsule(db::Capsule {
+                segment: db::Segment {
+                    a: array_to_vec3(a),
+                    b: array_to_vec3(b),
+                },
+                radius,
+            }),
+        }
+    }
+}
+
+impl From<db::CollisionShape> for SnapshotCollisionShape {
+    fn from(shape: db::CollisionShape) -> Self {
+        match shape {
+            db::CollisionShape::None => Self::None,
+            db::CollisionShape::Cuboid(cuboid) => Self::Cuboid {
+                half_extents: vec3_to_array(cuboid.half_extents),
+            },
+            db::CollisionShape::Ball(ball) => Self::Ball {
+                radius: ball.radius,
+            },
+            db::CollisionShape::Capsule(capsule) => Self::Capsule {
+                a: vec3_to_array(capsule.segment.a),
+                b: vec3_to_array(capsule.segment.b),
+                radius: capsule.radius,
+            },
+            db::CollisionShape::Heightfield(heightfield) => Self::Heightfield {
+                width: heightfield.width,
+                height: heightfield.height,
+                heights: heightfield.heights,
+                scale: vec3_to_array(heightfield.scale),
+            },
+            db::CollisionShape::ConvexHull(hull) => Self::ConvexHull {
+                points: hull.points.into_iter().map(vec3_to_array).collect(),
+                indices: hull
+                    .indices
+                    .into_iter()
+                    .map(|triangle| [triangle.v_1, triangle.v_2, triangle.v_3])
+                    .collect(),
+            },
+        }
+    }
+}
+
+impl From<SnapshotCollisionShape> for db::CollisionShape {
+    fn from(shape: SnapshotCollisionShape) -> Self {
+        match shape {
+            SnapshotCollisionShape::None => Self::None,
+            SnapshotCollisionShape::Cuboid { half_extents } => Self::Cuboid(db::Cuboid {
+                half_extents: array_to_vec3(half_extents),
+            }),
+            SnapshotCollisionShape::Ball { radius } => Self::Ball(db::Ball { radius }),
+            SnapshotCollisionShape::Capsule { a, b, radius } => Self::Capsule(db::Capsule {
+                segment: db::Segment {
+                    a: array_to_vec3(a),
+                    b: array_to_vec3(b),
+                },
+                radius,
+            }),
+            SnapshotCollisionShape::Heightfield {
+                width,
+                height,
+                heights,
+                scale,
+            } => Self::Heightfield(db::Heightfield {
+                width,
+                height,
+                heights,
+                scale: array_to_vec3(scale),
+            }),
+            SnapshotCollisionShape::ConvexHull { points, indices } => {
+                Self::ConvexHull(db::ConvexHull {
+                    points: points.into_iter().map(array_to_vec3).collect(),
+                    indices: indices
+                        .into_iter()
+                        .map(|[v_1, v_2, v_3]| db::Triangle { v_1, v_2, v_3 })
+                        .collect(),
+                })
+            }
+        }
+    }
+}
@@ -0,0 +1,75 @@
+//! Uploading the snapshot to the DB through the staged `*_world_import` reducers.
+//!
+//! All calls are sent back-to-back: the server runs one client's reducer calls in order, and
+//! `commit_world_import` refuses to run unless every chunk before it was staged.
+
+use crate::{
+    module_bindings::{
+        AssetKind, CollisionShape, WorldObject, append_world_import_chunk, begin_world_import,
+        commit_world_import,
+    },
+    spacetimedb::SpacetimeDB,
+};
+
+/// Rough upper bound for one chunk's encoded size. Heightfields can make single rows large,
+/// so chunks are cut by estimated bytes rather than by row count.
+const IMPORT_CHUNK_BYTES: usize = 256 * 1024;
+
+/// Send `rows` as a complete import. Returns the number of chunks sent.
+pub(super) fn send_import(stdb: &SpacetimeDB, rows: Vec<WorldObject>) -> Result<u32, String> {
+    stdb.reducers()
+        .begin_world_import(rows.len() as u64)
+        .map_err(send_err)?;
+
+    let chunks = chunk_rows(rows);
+    let chunk_count = chunks.len() as u32;
+    for (index, chunk) in chunks.into_iter().enumerate() {
+        stdb.reducers()
+            .append_world_import_chunk(index as u32, chunk)
+            .map_err(send_err)?;
+    }
+
+    stdb.reducers().commit_world_import().map_err(send_err)?;
+    Ok(chunk_count)
+}
+
+fn send_err(err: impl std::fmt::Display) -> String {
+    format!("Failed to send restore request: {err}")
+}
+
+fn chunk_rows(rows: Vec<WorldObject>) -> Vec<Vec<WorldObject>> {
+    let mut chunks = Vec::new();
+    let mut chunk = Vec::new();
+    let mut chunk_bytes = 0;
+
+    for row in rows {
+        let row_bytes = estimated_size(&row);
+        if !chunk.is_empty() && chunk_bytes + row_bytes > IMPORT_CHUNK_BYTES {
+            chunks.push(std::mem::take(&mut chunk));
+            chunk_bytes = 0;
+        }
+        chunk_bytes += row_bytes;
+        chunk.push(row);
+    }
+
+    if !chunk.is_empty() {
+        chunks.push(chunk);
+    }
+    chunks
+}
+
+/// Approximate BSATN size of a row: fixed-size fields plus the variable-length payloads.
+fn estimated_size(row: &WorldObject) -> usize {
+    let asset = match &row.asset {
+        AssetKind::Path(path) => path.len(),
+        AssetKind::PrimitiveShape(_) => 32,
+    };
+
+    let collision = match &row.collision_shape {
+        CollisionShape::Heightfield(heightfield) => heightfield.heights.len() * 4,
+        CollisionShape::ConvexHull(hull) => hull.points.len() * 12 + hull.indices.len() * 12,
+        _ => 32,
+    };
+
+    64 + asset + collision
+}
@@ -0,0 +1,476 @@
+//! Local world snapshot backup: `<project_root>/.editor/world_snapshot.ron`.
+//!
+//! The snapshot is a recovery mechanism for accidental DB wipes (`publish --delete-data`),
+//! not the primary storage format. It follows the subscribed `world_object` rows, whoever
+//! changed them, and is only ever written from that committed state, never on a timer.
+//!
+//! Writes are atomic: the new snapshot goes to a temp file that is renamed over the old one,
+//! and the previous snapshot is kept next to it as `world_snapshot.ron.prev`. Writes after
+//! changes wait for the changes to settle and run on the IO task pool, one at a time, so large
+//! worlds don't stall frames.
+
+mod fingerprint;
+mod format;
+mod import;
+mod sync;
+
+pub use format::{SCHEMA_VERSION, WorldSnapshot};
+pub use sync::{ResolveSync, RestoreProgress, SyncState};
+
+use core::time::Duration;
+use std::{
+    fmt,
+    fs::{self, File},
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use bevy::{
+    prelude::*,
+    tasks::{IoTaskPool, Task, block_on, futures::check_ready},
+};
+use bevy_spacetimedb::{ReadDeleteMessage, ReadInsertMessage, ReadUpdateMessage};
+use spacetimedb_sdk::Table;
+
+use crate::{
+    config::ClientRuntimeConfig,
+    module_bindings::{WorldObject, WorldObjectTableAccess},
+    pending_ops::PendingOps,
+    spacetimedb::SpacetimeDB,
+};
+use fingerprint::Fingerprint;
+
+const SNAPSHOT_FILE_NAME: &str = "world_snapshot.ron";
+
+/// How long after the last change to the table the snapshot is written, so a burst of
+/// commits (a drag, a multi-object edit) produces one write.
+const WRITE_DELAY: Duration = Duration::from_millis(500);
+
+pub(super) fn plugin(app: &mut App) {
+    let config = app
+        .world()
+        .get_resource::<ClientRuntimeConfig>()
+        .expect("ClientRuntimeConfig must be inserted before snapshot::plugin is added")
+        .clone();
+
+    let path = config.editor_dir().join(SNAPSHOT_FILE_NAME);
+    println!("World snapshot path: {}", path.display());
+
+    app.insert_resource(SnapshotStore {
+        path,
+        snapshot: None,
+        load_error: None,
+        dirty_since: None,
+        writing: None,
+    });
+    app.add_plugins(sync::plugin);
+    app.add_systems(Startup, load_snapshot);
+    app.add_systems(Update, write_on_change);
+}
+
+/// The snapshot file location and the most recently loaded or written snapshot.
+#[derive(Resource, Debug)]
+pub struct SnapshotStore {
+    pub path: PathBuf,
+    /// `None` until a snapshot has been read or written, or if none exists yet.
+    pub snapshot: Option<WorldSnapshot>,
+    /// Set when a snapshot file exists but couldn't be read, so it isn't mistaken for "no backup yet".
+    pub load_error: Option<String>,
+    /// When the table last changed without the snapshot following, as `Time::elapsed`.
+    dirty_since: Option<Duration>,
+    /// The background write in progress, if any.
+    writing: Option<Task<Result<WorldSnapshot, SnapshotError>>>,
+}
+
+impl SnapshotStore {
+    /// Atomically write `snapshot` to disk and remember it as the current snapshot.
+    ///
+    /// Blocks, after waiting for any background write so the two can't race. Only for rare,
+    /// user-driven writes; table changes are written by `write_on_change`.
+    pub fn write(&mut self, snapshot: WorldSnapshot) -> Result<(), SnapshotError> {
+        if let Some(task) = self.writing.take() {
+            self.finish_write(block_on(task));
+        }
+        write_snapshot(&self.path, &snapshot)?;
+        self.snapshot = Some(snapshot);
+        Ok(())
+    }
+
+    fn finish_write(&mut self, result: Result<WorldSnapshot, SnapshotError>) {
+        match result {
+            Ok(snapshot) => {
+                println!(
+                    "Wrote world snapshot with {} object(s)",
+                    snapshot.world_objects.len()
+                );
+                self.snapshot = Some(snapshot);
+            }
+            Err(err) => eprintln!("Failed to write world snapshot: {err}"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum SnapshotError {
+    /// No snapshot has been written at this path yet.
+    NotFound(PathBuf),
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+    Parse {
+        path: PathBuf,
+        message: String,
+    },
+    UnsupportedVersion {
+        path: PathBuf,
+        found: u32,
+    },
+    Serialize(String),
+}
+
+impl fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SnapshotError::NotFound(path) => {
+                write!(f, "no world snapshot at {}", path.display())
+            }
+            SnapshotError::Io { path, source } => {
+                write!(f, "failed to access {}: {source}", path.display())
+            }
+            SnapshotError::Parse { path, message } => {
+                write!(f, "world snapshot {} is corrupt: {message}", path.display())
+            }
+            SnapshotError::UnsupportedVersion { path, found } => write!(
+                f,
+                "world snapshot {} has schema_version {found}, but this editor only reads version {SCHEMA_VERSION}",
+                path.display()
+            ),
+            SnapshotError::Serialize(message) => {
+                write!(f, "failed to serialize world snapshot: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for SnapshotError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SnapshotError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Read and validate the snapshot at `path`.
+pub fn read_snapshot(path: &Path) -> Result<WorldSnapshot, SnapshotError> {
+    let text = fs::read_to_string(path).map_err(|source| {
+        if source.kind() == io::ErrorKind::NotFound {
+            SnapshotError::NotFound(path.to_path_buf())
+        } else {
+            SnapshotError::Io {
+                path: path.to_path_buf(),
+                source,
+            }
+        }
+    })?;
+
+    // Check the version first so a newer format reports a version error, not a parse error.
+    let header: format::SnapshotHeader =
+        ron::from_str(&text).map_err(|err| SnapshotError::Parse {
+            path: path.to_path_buf(),
+            message: err.to_string(),
+        })?;
+    if header.schema_version != SCHEMA_VERSION {
+        return Err(SnapshotError::UnsupportedVersion {
+            path: path.to_path_buf(),
+            found: header.schema_version,
+        });
+    }
+
+    ron::from_str(&text).map_err(|err| SnapshotError::Parse {
+        path: path.to_path_buf(),
+        message: err.to_string(),
+    })
+}
+
+/// Atomically replace the snapshot at `path`, keeping the old one as `<path>.prev`.
+pub fn write_snapshot(path: &Path, snapshot: &WorldSnapshot) -> Result<(), SnapshotError> {
+    let io_err = |path: &Path| {
+        let path = path.to_path_buf();
+        move |source| SnapshotError::Io { path, source }
+    };
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(io_err(dir))?;
+    }
+
+    // Struct names are omitted so the header can be parsed on its own (see `read_snapshot`).
+    let text = ron::ser::to_string_pretty(snapshot, ron::ser::PrettyConfig::default())
+        .map_err(|err| SnapshotError::Serialize(err.to_string()))?;
+
+    let tmp_path = sibling_path(path, "tmp");
+    {
+        let mut file = File::create(&tmp_path).map_err(io_err(&tmp_path))?;
+        file.write_all(text.as_bytes()).map_err(io_err(&tmp_path))?;
+        // Make sure the bytes are on disk before the rename makes them the snapshot.
+        file.sync_all().map_err(io_err(&tmp_path))?;
+    }
+
+    if path.exists() {
+        let prev_path = sibling_path(path, "prev");
+        fs::copy(path, &prev_path).map_err(io_err(&prev_path))?;
+    }
+
+    fs::rename(&tmp_path, path).map_err(io_err(path))?;
+    Ok(())
+}
+
+/// `world_snapshot.ron` -> `world_snapshot.ron.<suffix>`
+fn sibling_path(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".");
+    name.push(suffix);
+    path.with_file_name(name)
+}
+
+fn load_snapshot(mut store: ResMut<SnapshotStore>) {
+    match read_snapshot(&store.path) {
+        Ok(snapshot) => {
+            println!(
+                "Loaded world snapshot with {} object(s)",
+                snapshot.world_objects.len()
+            );
+            store.snapshot = Some(snapshot);
+        }
+        Err(SnapshotError::NotFound(path)) => {
+            println!("No world snapshot yet at {}", path.display());
+        }
+        Err(err) => {
+            eprintln!("Failed to load world snapshot: {err}");
+            store.load_error = Some(err.to_string());
+        }
+    }
+}
+
+/// Write the snapshot once the table has changed, none of this editor's ops are still in
+/// flight and nothing more has changed for `WRITE_DELAY`.
+///
+/// Every change counts, including other editors' and the rows a (re)subscription delivers, so
+/// the snapshot keeps up with the DB. Waiting for `PendingOps` to drain means a multi-op edit
+/// produces one write, and the file never captures a state the editor is still waiting on.
+/// The rows are copied here; the serialization and disk IO run on the IO task pool.
+#[allow(clippy::too_many_arguments)]
+fn write_on_change(
+    time: Res<Time>,
+    mut inserted: ReadInsertMessage<WorldObject>,
+    mut updated: ReadUpdateMessage<WorldObject>,
+    mut deleted: ReadDeleteMessage<WorldObject>,
+    pending: Res<PendingOps>,
+    sync: Res<SyncState>,
+    stdb: SpacetimeDB,
+    mut store: ResMut<SnapshotStore>,
+) {
+    // Drain every reader; a single change is enough to write.
+    let changed = inserted.read().count() + updated.read().count() + deleted.read().count();
+    if changed > 0 {
+        store.dirty_since = Some(time.elapsed());
+    }
+
+    // One write at a time, so an older snapshot can never land after a newer one.
+    if let Some(task) = &mut store.writing {
+        let Some(result) = check_ready(task) else {
+            return;
+        };
+        store.writing = None;
+        store.finish_write(result);
+    }
+
+    let Some(dirty_since) = store.dirty_since else {
+        return;
+    };
+    // Never let a DB we haven't reconciled with overwrite the backup.
+    if time.elapsed().saturating_sub(dirty_since) < WRITE_DELAY
+        || !pending.is_empty()
+        || !sync.allows_edits()
+    {
+        return;
+    }
+    store.dirty_since = None;
+
+    // Rewriting identical rows would only replace the `.prev` backup with a copy.
+    let snapshot = WorldSnapshot::from_rows(stdb.db().world_object().iter());
+    let unchanged = store.snapshot.as_ref().is_some_and(|written| {
+        Fingerprint::of(&written.world_objects) == Fingerprint::of(&snapshot.world_objects)
+    });
+    if unchanged {
+        return;
+    }
+
+    let path = store.path.clone();
+    store.writing = Some(
+        IoTaskPool::get().spawn(async move { write_snapshot(&path, &snapshot).map(|()| snapshot) }),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::format::{
+        SnapshotAssetKind, SnapshotCollisionShape, SnapshotPrimitiveShape, SnapshotWorldObject,
+    };
+    use super::*;
+
+    /// A fresh directory under the system temp dir, removed again when dropped.
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> Self {
+            let path = std::env::temp_dir().join(format!(
+                "editor-snapshot-test-{}-{name}",
+                std::process::id()
+            ));
+            let _ = fs::remove_dir_all(&path);
+            fs::create_dir_all(&path).unwrap();
+            Self(path)
+        }
+
+        fn snapshot_path(&self) -> PathBuf {
+            self.0.join(SNAPSHOT_FILE_NAME)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn object(id: u64, asset: SnapshotAssetKind) -> SnapshotWorldObject {
+        SnapshotWorldObject {
+            id,
+            asset,
+            translation: [1.5, -0.1, 1.0e6],
+            rotation: [0.0, 0.38268343, 0.0, 0.9238795],
+            scale: [1.0, 2.0, 0.25],
+            collision_shape: SnapshotCollisionShape::None,
+        }
+    }
+
+    fn sample_snapshot() -> WorldSnapshot {
+        let mut rock = object(7, SnapshotAssetKind::Path("props/rock.glb".into()));
+        rock.collision_shape = SnapshotCollisionShape::ConvexHull {
+            points: vec![
+                [0.0, 0.0, 0.0],
+                [1.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0],
+                [0.0, 0.0, 1.0],
+            ],
+            indices: vec![[0, 2, 1], [0, 1, 3], [0, 3, 2], [1, 2, 3]],
+        };
+        let mut ground = object(
+            9,
+            SnapshotAssetKind::PrimitiveShape(SnapshotPrimitiveShape::Cuboid {
+                half_extents: [10.0, 0.5, 10.0],
+            }),
+        );
+        ground.collision_shape = SnapshotCollisionShape::Heightfield {
+            width: 2,
+            height: 2,
+            heights: vec![0.0, 0.1, 0.2, 0.3],
+            scale: [20.0, 1.0, 20.0],
+        };
+
+        WorldSnapshot {
+            schema_version: SCHEMA_VERSION,
+            saved_at: Some(1_700_000_000),
+            world_objects: vec![rock, ground],
+        }
+    }
+
+    #[test]
+    fn write_then_read_round_trips() {
+        let dir = TempDir::new("round-trip");
+        let snapshot = sample_snapshot();
+
+        write_snapshot(&dir.snapshot_path(), &snapshot).unwrap();
+        assert_eq!(read_snapshot(&dir.snapshot_path()).unwrap(), snapshot);
+    }
+
+    #[test]
+    fn write_keeps_previous_snapshot_and_no_temp_file() {
+        let dir = TempDir::new("prev");
+        let path = dir.snapshot_path();
+        let first = sample_snapshot();
+        let mut second = sample_snapshot();
+        second.world_objects.pop();
+
+        write_snapshot(&path, &first).unwrap();
+        write_snapshot(&path, &second).unwrap();
+
+        assert_eq!(read_snapshot(&path).unwrap(), second);
+        assert_eq!(read_snapshot(&sibling_path(&path, "prev")).unwrap(), first);
+        assert!(!sibling_path(&path, "tmp").exists());
+    }
+
+    #[test]
+    fn write_creates_missing_directories() {
+        let dir = TempDir::new("nested");
+        let path = dir.0.join(".editor").join(SNAPSHOT_FILE_NAME);
+
+        write_snapshot(&path, &sample_snapshot()).unwrap();
+        assert!(read_snapshot(&path).is_ok());
+    }
+
+    #[test]
+    fn missing_file_is_not_found() {
+        let dir = TempDir::new("missing");
+
+        let err = read_snapshot(&dir.snapshot_path()).unwrap_err();
+        assert!(matches!(err, SnapshotError::NotFound(path) if path == dir.snapshot_path()));
+    }
+
+    #[test]
+    fn corrupt_file_is_a_parse_error() {
+        let dir = TempDir::new("corrupt");
+        fs::write(
+            dir.snapshot_path(),
+            "(schema_version: 1, world_objects: [(id: ",
+        )
+        .unwrap();
+
+        let err = read_snapshot(&dir.snapshot_path()).unwrap_err();
+        assert!(matches!(err, SnapshotError::Parse { .. }), "{err}");
+        assert!(err.to_string().contains("is corrupt"), "{err}");
+    }
+
+    #[test]
+    fn other_schema_version_is_rejected() {
+        let dir = TempDir::new("version");
+        let mut snapshot = sample_snapshot();
+        snapshot.schema_version = SCHEMA_VERSION + 1;
+        write_snapshot(&dir.snapshot_path(), &snapshot).unwrap();
+
+        let err = read_snapshot(&dir.snapshot_path()).unwrap_err();
+        assert!(
+            matches!(err, SnapshotError::UnsupportedVersion { found, .. } if found == SCHEMA_VERSION + 1),
+            "{err}"
+        );
+        assert!(err.to_string().contains("schema_version"), "{err}");
+    }
+
+    #[test]
+    fn newer_format_reports_version_not_parse_error() {
+        let dir = TempDir::new("newer");
+        fs::write(
+            dir.snapshot_path(),
+            "(schema_version: 99, objects_by_layer: {\"default\": []})",
+        )
+        .unwrap();
+
+        let err = read_snapshot(&dir.snapshot_path()).unwrap_err();
+        assert!(
+            matches!(err, SnapshotError::UnsupportedVersion { found: 99, .. }),
+            "{err}"
+        );
+    }
+}
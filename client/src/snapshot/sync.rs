@@ -0,0 +1,298 @@
+//! Out-of-sync detection between the local snapshot and the `world_object` table.
+//!
+//! Once the subscription has delivered its rows, both sides are fingerprinted. If they differ
+//! at all (typically because the DB was wiped), the editor enters [`SyncState::OutOfSync`] and
+//! every world edit is blocked until the user picks a side with a [`ResolveSync`] message.
+//! Once in sync, the snapshot follows the table; the two are only compared again after a
+//! restore or a re-subscription.
+
+use bevy::{platform::collections::HashMap, prelude::*};
+use bevy_spacetimedb::ReadReducerMessage;
+use spacetimedb_sdk::{Status, Table};
+
+use super::{
+    SnapshotStore, WorldSnapshot, fingerprint::Fingerprint, format::SnapshotWorldObject, import,
+};
+use crate::{
+    module_bindings::{WorldObjectTableAccess, cancel_world_import},
+    spacetimedb::{AppendWorldImportChunk, CommitWorldImport, SpacetimeDB, WorldSubscription},
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<SyncState>();
+    app.add_message::<ResolveSync>();
+    app.add_systems(
+        Update,
+        (
+            check_sync,
+            resolve_sync,
+            on_import_progress,
+            on_import_result,
+        )
+            .chain(),
+    );
+}
+
+/// Whether the editor may currently change the world.
+#[derive(Resource, Debug, Default, Clone, PartialEq)]
+pub enum SyncState {
+    /// The subscription hasn't delivered its rows yet, so there's nothing to compare against.
+    #[default]
+    WaitingForDb,
+    InSync,
+    OutOfSync(SyncMismatch),
+    /// "Accept snapshot" was chosen and the import hasn't been committed yet.
+    Restoring(RestoreProgress),
+}
+
+impl SyncState {
+    pub fn allows_edits(&self) -> bool {
+        matches!(self, SyncState::InSync)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SyncMismatch {
+    pub db: Fingerprint,
+    /// `None` if the snapshot file exists but couldn't be read.
+    pub snapshot: Option<Fingerprint>,
+    /// How many of the snapshot's objects the DB doesn't have.
+    pub missing: usize,
+    /// How many of the snapshot's objects the DB has with different contents.
+    pub changed: usize,
+    /// Why the last attempt to load or resolve failed, if it did.
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RestoreProgress {
+    pub chunks_sent: u32,
+    pub chunks_staged: u32,
+    /// The first chunk failure, which explains the commit failure that follows it.
+    pub error: Option<String>,
+}
+
+/// The user's choice in the out-of-sync dialog. Both options are destructive.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResolveSync {
+    /// Replace every `world_object` row with the snapshot's rows.
+    AcceptSnapshot,
+    /// Overwrite the snapshot file with the current table contents.
+    AcceptDb,
+}
+
+fn db_rows(stdb: &SpacetimeDB) -> Vec<SnapshotWorldObject> {
+    stdb.db().world_object().iter().map(Into::into).collect()
+}
+
+/// Compare the table with the loaded snapshot.
+fn compare(stdb: &SpacetimeDB, store: &SnapshotStore) -> SyncState {
+    let rows = db_rows(stdb);
+    let db = Fingerprint::of(&rows);
+
+    let Some(snapshot) = &store.snapshot else {
+        return SyncState::OutOfSync(SyncMismatch {
+            db,
+            snapshot: None,
+            missing: 0,
+            changed: 0,
+            error: store.load_error.clone(),
+        });
+    };
+
+    let fingerprint = Fingerprint::of(&snapshot.world_objects);
+    if fingerprint == db {
+        return SyncState::InSync;
+    }
+
+    // Any difference counts, not just missing rows: a wipe followed by a reseed restarts ids
+    // at 1, so the DB can have every id the snapshot has and still hold other objects.
+    let db_rows: HashMap<u64, &SnapshotWorldObject> =
+        rows.iter().map(|row| (row.id, row)).collect();
+    let mut missing = 0;
+    let mut changed = 0;
+    for row in &snapshot.world_objects {
+        match db_rows.get(&row.id) {
+            None => missing += 1,
+            Some(db_row) if *db_row != row => changed += 1,
+            Some(_) => {}
+        }
+    }
+
+    SyncState::OutOfSync(SyncMismatch {
+        db,
+        snapshot: Some(fingerprint),
+        missing,
+        changed,
+        error: None,
+    })
+}
+
+fn check_sync(
+    subscription: Res<WorldSubscription>,
+    stdb: SpacetimeDB,
+    mut store: ResMut<SnapshotStore>,
+    mut state: ResMut<SyncState>,
+) {
+    // A reconnect re-subscribes, so the table has to be compared again once it's back.
+    if !subscription.is_applied() {
+        if *state != SyncState::WaitingForDb {
+            *state = SyncState::WaitingForDb;
+        }
+        return;
+    }
+
+    if *state != SyncState::WaitingForDb {
+        return;
+    }
+
+    // First run for this project: there's no backup to protect yet, so start one from the DB.
+    // It's in sync by construction; there's nothing to compare.
+    if store.snapshot.is_none() && store.load_error.is_none() {
+        let snapshot = WorldSnapshot::from_rows(stdb.db().world_object().iter());
+        match store.write(snapshot) {
+            Ok(()) => {
+                println!("Started a world snapshot from the DB");
+                *state = SyncState::InSync;
+                return;
+            }
+            Err(err) => {
+                eprintln!("Failed to write initial world snapshot: {err}");
+                store.load_error = Some(err.to_string());
+            }
+        }
+    }
+
+    let new_state = compare(&stdb, &store);
+    match &new_state {
+        SyncState::OutOfSync(mismatch) => {
+            eprintln!(
+                "World is out of sync: DB has {}, snapshot has {}, {} missing from and {} changed in the DB",
+                mismatch.db,
+                mismatch
+                    .snapshot
+                    .map_or_else(|| "no readable data".to_string(), |fp| fp.to_string()),
+                mismatch.missing,
+                mismatch.changed
+            );
+        }
+        _ => println!("World snapshot matches the DB"),
+    }
+    *state = new_state;
+}
+
+fn resolve_sync(
+    mut requests: MessageReader<ResolveSync>,
+    stdb: SpacetimeDB,
+    mut store: ResMut<SnapshotStore>,
+    mut state: ResMut<SyncState>,
+) {
+    for request in requests.read() {
+        let SyncState::OutOfSync(mismatch) = &*state else {
+            continue;
+        };
+        let mut mismatch = mismatch.clone();
+
+        match request {
+            ResolveSync::AcceptDb => {
+                let snapshot = WorldSnapshot::from_rows(stdb.db().world_object().iter());
+                match store.write(snapshot) {
+                    Ok(()) => {
+                        println!("Overwrote world snapshot with the DB contents");
+                        store.load_error = None;
+                        *state = SyncState::InSync;
+                    }
+                    Err(err) => {
+                        mismatch.error = Some(format!("Failed to write snapshot: {err}"));
+                        *state = SyncState::OutOfSync(mismatch);
+                    }
+                }
+            }
+            ResolveSync::AcceptSnapshot => {
+                let Some(snapshot) = &store.snapshot else {
+                    continue;
+                };
+                match import::send_import(&stdb, snapshot.to_rows()) {
+                    Ok(chunks_sent) => {
+                        println!(
+                            "Restoring {} object(s) from the world snapshot in {chunks_sent} chunk(s)",
+                            snapshot.world_objects.len()
+                        );
+                        *state = SyncState::Restoring(RestoreProgress {
+                            chunks_sent,
+                            chunks_staged: 0,
+                            error: None,
+                        });
+                    }
+                    Err(err) => {
+                        let _ = stdb.reducers().cancel_world_import();
+                        mismatch.error = Some(err);
+                        *state = SyncState::OutOfSync(mismatch);
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn failure_message(status: &Status) -> Option<String> {
+    match status {
+        Status::Committed => None,
+        Status::Failed(message) => Some(format!("Restore failed: {message}")),
+        Status::OutOfEnergy => Some("Restore failed: reducer ran out of energy".to_string()),
+    }
+}
+
+fn on_import_progress(
+    mut chunks: ReadReducerMessage<AppendWorldImportChunk>,
+    stdb: SpacetimeDB,
+    mut state: ResMut<SyncState>,
+) {
+    for chunk in chunks.read().map(|message| &message.result) {
+        let SyncState::Restoring(progress) = &mut *state else {
+            continue;
+        };
+        if Some(chunk.event.caller_identity) != stdb.try_identity() {
+            continue;
+        }
+
+        match failure_message(&chunk.event.status) {
+            None => progress.chunks_staged += 1,
+            Some(error) => {
+                progress.error.get_or_insert(error);
+            }
+        }
+    }
+}
+
+/// Finish a restore once the commit reports back.
+fn on_import_result(
+    mut results: ReadReducerMessage<CommitWorldImport>,
+    stdb: SpacetimeDB,
+    store: Res<SnapshotStore>,
+    mut state: ResMut<SyncState>,
+) {
+    for result in results.read().map(|message| &message.result) {
+        let SyncState::Restoring(progress) = &*state else {
+            continue;
+        };
+        if Some(result.event.caller_identity) != stdb.try_identity() {
+            continue;
+        }
+
+        // The cache already holds the imported rows when the commit succeeded,
+        // so comparing again should now report them as equal.
+        let error = failure_message(&result.event.status).map(|error| {
+            // Don't leave the staged rows lying around on the server.
+            let _ = stdb.reducers().cancel_world_import();
+            progress.error.clone().unwrap_or(error)
+        });
+
+        let mut new_state = compare(&stdb, &store);
+        if let (SyncState::OutOfSync(mismatch), Some(error)) = (&mut new_state, error) {
+            eprintln!("{error}");
+            mismatch.error = Some(error);
+        }
+        *state = new_state;
+    }
+}
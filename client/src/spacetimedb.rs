@@ -1,7 +1,15 @@
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, Ordering},
+};
+
 use crate::config::ClientRuntimeConfig;
 use crate::module_bindings::{
-    DbConnection, Reducer, RemoteModule, RemoteReducers, RemoteTables, WorldObjectTableAccess,
-    move_object, rotate_object, scale_object,
+    DbConnection, EditLogTableAccess, EditorPresenceTableAccess, ObjectLockTableAccess, Reducer,
+    RemoteModule, RemoteReducers, RemoteTables, TransformPreviewTableAccess, WorldObject,
+    WorldObjectTableAccess, append_world_import_chunk, commit_world_import, delete_object,
+    delete_objects, insert_object, move_object, prune_edit_log, restore_object, rotate_object,
+    scale_object, set_collision_shape,
 };
 use bevy::prelude::*;
 use bevy_spacetimedb::{
@@ -11,6 +19,13 @@ use spacetimedb_sdk::ReducerEvent;
 
 pub type SpacetimeDB<'a> = Res<'a, StdbConnection<DbConnection>>;
 
+#[allow(dead_code)]
+#[derive(Debug, RegisterReducerMessage)]
+pub struct InsertObject {
+    pub event: ReducerEvent<Reducer>,
+    pub object: crate::module_bindings::WorldObject,
+}
+
 #[allow(dead_code)]
 #[derive(Debug, RegisterReducerMessage)]
 pub struct MoveObject {
@@ -32,6 +47,64 @@ pub struct ScaleObject {
     pub id: u64,
     pub scale: crate::module_bindings::Vec3,
 }
+#[allow(dead_code)]
+#[derive(Debug, RegisterReducerMessage)]
+pub struct SetCollisionShape {
+    pub event: ReducerEvent<Reducer>,
+    pub id: u64,
+    pub collision_shape: crate::module_bindings::CollisionShape,
+}
+#[allow(dead_code)]
+#[derive(Debug, RegisterReducerMessage)]
+pub struct RestoreObject {
+    pub event: ReducerEvent<Reducer>,
+    pub object: crate::module_bindings::WorldObject,
+}
+#[allow(dead_code)]
+#[derive(Debug, RegisterReducerMessage)]
+pub struct DeleteObject {
+    pub event: ReducerEvent<Reducer>,
+    pub id: u64,
+}
+#[allow(dead_code)]
+#[derive(Debug, RegisterReducerMessage)]
+pub struct DeleteObjects {
+    pub event: ReducerEvent<Reducer>,
+    pub ids: Vec<u64>,
+}
+#[allow(dead_code)]
+#[derive(Debug, RegisterReducerMessage)]
+pub struct PruneEditLog {
+    pub event: ReducerEvent<Reducer>,
+    pub older_than: spacetimedb_sdk::Timestamp,
+}
+#[allow(dead_code)]
+#[derive(Debug, RegisterReducerMessage)]
+pub struct AppendWorldImportChunk {
+    pub event: ReducerEvent<Reducer>,
+    pub chunk_index: u32,
+    pub objects: Vec<WorldObject>,
+}
+#[allow(dead_code)]
+#[derive(Debug, RegisterReducerMessage)]
+pub struct CommitWorldImport {
+    pub event: ReducerEvent<Reducer>,
+}
+
+/// Whether the `world_object` subscription has delivered its initial rows.
+///
+/// Until it has, an empty table means "not loaded yet", not "the world is empty".
+/// The flag is set from the SDK's `on_applied` callback, which runs off the main thread.
+#[derive(Resource, Default, Clone)]
+pub struct WorldSubscription {
+    applied: Arc<AtomicBool>,
+}
+
+impl WorldSubscription {
+    pub fn is_applied(&self) -> bool {
+        self.applied.load(Ordering::Acquire)
+    }
+}
 
 pub(super) fn plugin(app: &mut App) {
     // Read env-based settings once at startup (the config resource is inserted in `main.rs`)
@@ -52,24 +125,51 @@ pub(super) fn plugin(app: &mut App) {
             .with_uri(config.spacetime_url)
             .with_module_name(config.spacetime_name)
             .add_table(RemoteTables::world_object)
+            .add_table(RemoteTables::edit_log)
+            .add_table(RemoteTables::transform_preview)
+            .add_table(RemoteTables::editor_presence)
+            .add_table(RemoteTables::object_lock)
+            .add_reducer::<InsertObject>()
             .add_reducer::<MoveObject>()
             .add_reducer::<RotateObject>()
             .add_reducer::<ScaleObject>()
+            .add_reducer::<SetCollisionShape>()
+            .add_reducer::<RestoreObject>()
+            .add_reducer::<DeleteObject>()
+            .add_reducer::<DeleteObjects>()
+            .add_reducer::<PruneEditLog>()
+            .add_reducer::<AppendWorldImportChunk>()
+            .add_reducer::<CommitWorldImport>()
             .with_run_fn(DbConnection::run_threaded),
     );
 
+    app.init_resource::<WorldSubscription>();
     app.add_systems(Update, on_connect);
 }
 
-fn on_connect(mut messages: ReadStdbConnectedMessage, stdb: SpacetimeDB) {
+fn on_connect(
+    mut messages: ReadStdbConnectedMessage,
+    stdb: SpacetimeDB,
+    subscription: Res<WorldSubscription>,
+) {
     for message in messages.read() {
         println!("SpacetimeDB module connected: {:?}", message.identity);
 
-        // Subscribe to the tables we actually use.
-        let queries = vec!["SELECT * FROM world_object"];
+        // Subscribe to the tables we actually use. The history panel subscribes to the slice
+        // of `edit_log` it shows by itself.
+        let queries = vec![
+            "SELECT * FROM world_object",
+            "SELECT * FROM transform_preview",
+            "SELECT * FROM editor_presence",
+            "SELECT * FROM object_lock",
+        ];
         println!("SpacetimeDB subscribing to: {:?}", queries);
 
-        stdb.subscription_builder().subscribe(queries);
+        subscription.applied.store(false, Ordering::Release);
+        let applied = subscription.applied.clone();
+        stdb.subscription_builder()
+            .on_applied(move |_| applied.store(true, Ordering::Release))
+            .subscribe(queries);
     }
 }
 
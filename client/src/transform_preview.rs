@@ -0,0 +1,154 @@
+//! Live drag previews through the `transform_preview` table.
+//!
+//! Drags only commit on release. While one is in progress, the selected objects' transforms
+//! are published at `PREVIEW_RATE_HZ` so other editors see the motion live; once the drag ends
+//! (committed or cancelled) the previews are cleared again. The DB also drops them when the
+//! editor disconnects.
+//!
+//! Other editors' previews are applied to the rendered entities until their row is cleared,
+//! at which point the entity goes back to what the `world_object` row says.
+
+use core::time::Duration;
+
+use bevy::{platform::collections::HashMap, prelude::*};
+use bevy_spacetimedb::{ReadDeleteMessage, ReadInsertMessage, ReadUpdateMessage};
+
+use crate::{
+    module_bindings::{
+        PreviewTransform, TransformPreview, WorldObjectTableAccess, clear_transform_previews,
+        preview_transforms,
+    },
+    selection::Selection,
+    spacetimedb::SpacetimeDB,
+    ui::transform_tools::TransformTool,
+    world_object::{ObjectId, WorldObjectIndex, row_transform},
+};
+
+/// How often in-progress drags are published.
+const PREVIEW_RATE_HZ: f32 = 15.0;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(Update, (publish_previews, apply_remote_previews));
+}
+
+/// What this editor has published for the drag in progress.
+#[derive(Default)]
+struct PublishedPreviews {
+    /// `Time::elapsed` of the last publish.
+    last_sent: Duration,
+    /// The last transform published per row id.
+    sent: HashMap<u64, Transform>,
+}
+
+/// Publish the selected objects' transforms while a drag is in progress, and clear them after.
+fn publish_previews(
+    time: Res<Time>,
+    tool: Res<TransformTool>,
+    selection: Res<Selection>,
+    index: Res<WorldObjectIndex>,
+    objects: Query<&Transform, With<ObjectId>>,
+    stdb: SpacetimeDB,
+    mut published: Local<PublishedPreviews>,
+) {
+    if !tool.is_active {
+        // The drag's final reducer calls were sent before this runs, so the clear lands after them.
+        if !published.sent.is_empty() {
+            published.sent.clear();
+            if let Err(err) = stdb.reducers().clear_transform_previews() {
+                eprintln!("Failed to clear transform previews: {err}");
+            }
+        }
+        return;
+    }
+
+    let now = time.elapsed();
+    if now.saturating_sub(published.last_sent) < Duration::from_secs_f32(1.0 / PREVIEW_RATE_HZ) {
+        return;
+    }
+
+    let table = stdb.db().world_object();
+    let mut previews = Vec::new();
+    for id in selection.ids() {
+        let (Some(entity), Some(row)) = (index.get(*id), table.id().find(id)) else {
+            continue;
+        };
+        let Ok(transform) = objects.get(entity) else {
+            continue;
+        };
+
+        // Only publish what moved since the last publish (or since the row, on the first one).
+        let last = published
+            .sent
+            .get(id)
+            .copied()
+            .unwrap_or_else(|| row_transform(&row));
+        if *transform == last {
+            continue;
+        }
+
+        published.sent.insert(*id, *transform);
+        previews.push(PreviewTransform {
+            object_id: *id,
+            translation: transform.translation.into(),
+            rotation: transform.rotation.into(),
+            scale: transform.scale.into(),
+        });
+    }
+
+    if previews.is_empty() {
+        return;
+    }
+    published.last_sent = now;
+    if let Err(err) = stdb.reducers().preview_transforms(previews) {
+        eprintln!("Failed to publish transform previews: {err}");
+    }
+}
+
+/// Move other editors' dragged objects along with their previews.
+fn apply_remote_previews(
+    mut inserted: ReadInsertMessage<TransformPreview>,
+    mut updated: ReadUpdateMessage<TransformPreview>,
+    mut deleted: ReadDeleteMessage<TransformPreview>,
+    stdb: SpacetimeDB,
+    index: Res<WorldObjectIndex>,
+    mut objects: Query<&mut Transform, With<ObjectId>>,
+) {
+    let own_identity = stdb.try_identity();
+    let previews = inserted
+        .read()
+        .map(|msg| &msg.row)
+        .chain(updated.read().map(|msg| &msg.new));
+
+    for preview in previews {
+        if Some(preview.owner) == own_identity {
+            continue;
+        }
+        let Some(entity) = index.get(preview.object_id) else {
+            continue;
+        };
+        if let Ok(mut transform) = objects.get_mut(entity) {
+            *transform = Transform {
+                translation: preview.translation.clone().into(),
+                rotation: preview.rotation.clone().into(),
+                scale: preview.scale.clone().into(),
+            };
+        }
+    }
+
+    // The drag ended: show the row again, which by now holds the committed transform.
+    let table = stdb.db().world_object();
+    for msg in deleted.read() {
+        if Some(msg.row.owner) == own_identity {
+            continue;
+        }
+        let (Some(entity), Some(row)) = (
+            index.get(msg.row.object_id),
+            table.id().find(&msg.row.object_id),
+        ) else {
+            continue;
+        };
+        if let Ok(mut transform) = objects.get_mut(entity) {
+            *transform = row_transform(&row);
+        }
+    }
+}
@@ -5,21 +5,30 @@ use bevy::{app::App, app::AppExit, ecs::message::MessageWriter, ecs::system::Res
 use bevy_egui::{EguiContexts, EguiPrimaryContextPass, egui};
 
 use crate::ui::asset_browser::AssetBrowserUiState;
+use crate::ui::history::HistoryUiState;
+use crate::ui::inspector::InspectorUiState;
+use crate::ui::outliner::OutlinerUiState;
 use crate::ui::performance::PerformanceUiState;
-use crate::ui::transform_tools::TransformTool;
+use crate::ui::transform_tools::{SnapSettings, TransformTool};
 
 pub(super) fn plugin(app: &mut App) {
     // Render panels in the egui pass schedule so the pass state is initialized.
     app.add_systems(EguiPrimaryContextPass, render);
 }
 
+#[allow(clippy::too_many_arguments)]
 fn render(
     mut contexts: EguiContexts,
     exit: MessageWriter<AppExit>,
     perf_ui: ResMut<PerformanceUiState>,
     asset_browser_ui: ResMut<AssetBrowserUiState>,
+    inspector_ui: ResMut<InspectorUiState>,
+    outliner_ui: ResMut<OutlinerUiState>,
+    history_ui: ResMut<HistoryUiState>,
     grid_enabled: ResMut<crate::infinite_grid::InfiniteGridEnabled>,
+    colliders_enabled: ResMut<crate::colliders::ColliderWireframesEnabled>,
     mut active_tool: ResMut<TransformTool>,
+    mut snap_settings: ResMut<SnapSettings>,
 ) {
     let ctx = contexts.ctx_mut().expect("to get primary egui context");
 
@@ -30,10 +39,21 @@ fn render(
             ui.horizontal_centered(|ui| {
                 egui::MenuBar::new().ui(ui, |ui| {
                     file_menu::render(ui, exit);
-                    view_menu::render(ui, perf_ui, asset_browser_ui, grid_enabled);
+                    view_menu::render(
+                        ui,
+                        perf_ui,
+                        asset_browser_ui,
+                        inspector_ui,
+                        outliner_ui,
+                        history_ui,
+                        grid_enabled,
+                        colliders_enabled,
+                    );
 
                     ui.separator();
                     crate::ui::transform_tools::render_toolbar(ui, &mut active_tool);
+                    ui.separator();
+                    crate::ui::transform_tools::render_snapping(ui, &mut snap_settings);
 
                     // Fill the rest of the bar so it visually spans the full width.
                     ui.add_space(ui.available_width());
@@ -1,15 +1,24 @@
 use bevy::ecs::system::ResMut;
 use bevy_egui::egui::Ui;
 
+use crate::colliders::ColliderWireframesEnabled;
 use crate::infinite_grid::InfiniteGridEnabled;
 use crate::ui::asset_browser::AssetBrowserUiState;
+use crate::ui::history::HistoryUiState;
+use crate::ui::inspector::InspectorUiState;
+use crate::ui::outliner::OutlinerUiState;
 use crate::ui::performance::PerformanceUiState;
 
+#[allow(clippy::too_many_arguments)]
 pub(super) fn render(
     ui: &mut Ui,
     mut perf_ui: ResMut<PerformanceUiState>,
     mut asset_browser_ui: ResMut<AssetBrowserUiState>,
+    mut inspector_ui: ResMut<InspectorUiState>,
+    mut outliner_ui: ResMut<OutlinerUiState>,
+    mut history_ui: ResMut<HistoryUiState>,
     mut grid_enabled: ResMut<InfiniteGridEnabled>,
+    mut colliders_enabled: ResMut<ColliderWireframesEnabled>,
 ) {
     ui.menu_button("View", |ui| {
         if ui.button("Performance").clicked() {
@@ -22,9 +31,29 @@ pub(super) fn render(
             ui.close();
         }
 
+        if ui.button("Outliner").clicked() {
+            outliner_ui.visible = !outliner_ui.visible;
+            ui.close();
+        }
+
+        if ui.button("Details").clicked() {
+            inspector_ui.visible = !inspector_ui.visible;
+            ui.close();
+        }
+
+        if ui.button("History").clicked() {
+            history_ui.visible = !history_ui.visible;
+            ui.close();
+        }
+
         if ui.button("Grid").clicked() {
             grid_enabled.0 = !grid_enabled.0;
             ui.close();
         }
+
+        if ui.button("Colliders").clicked() {
+            colliders_enabled.0 = !colliders_enabled.0;
+            ui.close();
+        }
     });
 }
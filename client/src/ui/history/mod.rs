@@ -0,0 +1,517 @@
+//! History: the `edit_log` audit table, newest first.
+//!
+//! Every committed world edit leaves one entry per touched object with its author and the row
+//! before and after. The list can be narrowed to one object (or the current selection) and to
+//! one author. Like the outliner it is virtualized and only rebuilt when the log, the filters
+//! or, while filtering by selection, the selection changes.
+//!
+//! Entries carry whole rows, so the log is only subscribed to while the panel is open, and
+//! only as many days back as it shows.
+//!
+//! Clicking an entry selects its object. Admins can prune entries older than a number of days.
+
+use std::cmp::Reverse;
+
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, EguiPrimaryContextPass, egui};
+use bevy_spacetimedb::{ReadDeleteMessage, ReadInsertMessage, ReadReducerMessage};
+use spacetimedb_sdk::{Identity, Status, SubscriptionHandle as _, Table, Timestamp};
+
+use crate::{
+    module_bindings::{
+        EditLog, EditLogTableAccess, SubscriptionHandle, WorldObject, WorldObjectTableAccess,
+        prune_edit_log,
+    },
+    selection::Selection,
+    spacetimedb::{PruneEditLog, SpacetimeDB},
+};
+
+const ROW_HEIGHT: f32 = 18.0;
+const WHEN_COLUMN_WIDTH: f32 = 72.0;
+const AUTHOR_COLUMN_WIDTH: f32 = 140.0;
+const OBJECT_COLUMN_WIDTH: f32 = 72.0;
+
+const MICROS_PER_DAY: i64 = 24 * 60 * 60 * 1_000_000;
+
+#[derive(Resource)]
+pub struct HistoryUiState {
+    pub visible: bool,
+    object: ObjectFilter,
+    /// Text of the object id field, used with `ObjectFilter::Id`.
+    object_id: String,
+    author: Option<Identity>,
+    /// How many days of the log are loaded.
+    window_days: u32,
+    /// The value in the window field, applied to `window_days` once the user is done with it.
+    window_days_input: u32,
+    prune_days: u32,
+    /// Why the last prune was refused, if it was.
+    prune_error: Option<String>,
+}
+
+impl Default for HistoryUiState {
+    fn default() -> Self {
+        Self {
+            visible: false,
+            object: ObjectFilter::Any,
+            object_id: String::new(),
+            author: None,
+            window_days: 7,
+            window_days_input: 7,
+            prune_days: 30,
+            prune_error: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ObjectFilter {
+    Any,
+    Selected,
+    Id,
+}
+
+impl ObjectFilter {
+    fn label(self) -> &'static str {
+        match self {
+            ObjectFilter::Any => "Any object",
+            ObjectFilter::Selected => "Selected objects",
+            ObjectFilter::Id => "Object ID",
+        }
+    }
+}
+
+/// One listed entry, with its labels formatted once per rebuild.
+struct HistoryRow {
+    object_id: u64,
+    at: Timestamp,
+    author: Identity,
+    reducer: String,
+    change: String,
+}
+
+/// The entries currently listed, filtered and newest first.
+#[derive(Resource, Default)]
+struct HistoryRows {
+    rows: Vec<HistoryRow>,
+    /// Every author in the log, for the author filter.
+    authors: Vec<Identity>,
+    /// Set when the log, a filter or the filtered selection changed since the last rebuild.
+    dirty: bool,
+}
+
+/// The panel's `edit_log` subscription, if it's open.
+#[derive(Resource, Default)]
+struct HistorySubscription {
+    handle: Option<SubscriptionHandle>,
+    /// The window the handle covers, in days.
+    window_days: Option<u32>,
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<HistoryUiState>();
+    app.init_resource::<HistorySubscription>();
+    app.insert_resource(HistoryRows {
+        rows: Vec::new(),
+        authors: Vec::new(),
+        dirty: true,
+    });
+    app.add_systems(
+        Update,
+        (sync_subscription, mark_dirty_on_log_change, on_prune_result),
+    );
+    // Render panels in the egui pass schedule so the pass state is initialized.
+    app.add_systems(EguiPrimaryContextPass, render);
+}
+
+/// Subscribe to the shown window of the log while the panel is open, and drop it when closed.
+fn sync_subscription(
+    ui_state: Res<HistoryUiState>,
+    stdb: SpacetimeDB,
+    mut subscription: ResMut<HistorySubscription>,
+) {
+    let wanted = ui_state.visible.then_some(ui_state.window_days);
+    if wanted == subscription.window_days || stdb.try_identity().is_none() {
+        return;
+    }
+
+    // Rows only the old query covered leave the cache, which marks the list dirty.
+    if let Some(handle) = subscription.handle.take()
+        && let Err(err) = handle.unsubscribe()
+    {
+        eprintln!("Failed to unsubscribe from the edit log: {err}");
+    }
+    subscription.window_days = wanted;
+
+    if let Some(days) = wanted {
+        let now = Timestamp::now().to_micros_since_unix_epoch();
+        let since = Timestamp::from_micros_since_unix_epoch(now - i64::from(days) * MICROS_PER_DAY);
+        let query = format!("SELECT * FROM edit_log WHERE at > '{since}'");
+        subscription.handle = Some(stdb.subscription_builder().subscribe(query));
+    }
+}
+
+fn mark_dirty_on_log_change(
+    mut inserted: ReadInsertMessage<EditLog>,
+    mut deleted: ReadDeleteMessage<EditLog>,
+    mut rows: ResMut<HistoryRows>,
+) {
+    // Drain every reader; a single change is enough to rebuild.
+    let changed = inserted.read().count() + deleted.read().count();
+    if changed > 0 {
+        rows.dirty = true;
+    }
+}
+
+/// Show why a prune this editor asked for was refused (e.g. it isn't an admin).
+fn on_prune_result(
+    mut results: ReadReducerMessage<PruneEditLog>,
+    stdb: SpacetimeDB,
+    mut ui_state: ResMut<HistoryUiState>,
+) {
+    for result in results.read().map(|message| &message.result) {
+        if Some(result.event.caller_identity) != stdb.try_identity() {
+            continue;
+        }
+
+        ui_state.prune_error = match &result.event.status {
+            Status::Committed => None,
+            Status::Failed(message) => Some(format!("Prune failed: {message}")),
+            Status::OutOfEnergy => Some("Prune failed: reducer ran out of energy".to_string()),
+        };
+        if let Some(error) = &ui_state.prune_error {
+            eprintln!("{error}");
+        }
+    }
+}
+
+fn render(
+    mut contexts: EguiContexts,
+    mut ui_state: ResMut<HistoryUiState>,
+    mut rows: ResMut<HistoryRows>,
+    mut selection: ResMut<Selection>,
+    stdb: SpacetimeDB,
+) {
+    if !ui_state.visible {
+        return;
+    }
+
+    if ui_state.object == ObjectFilter::Selected && selection.is_changed() {
+        rows.dirty = true;
+    }
+
+    let ctx = contexts.ctx_mut().expect("to get primary egui context");
+    let own_identity = stdb.try_identity();
+    let mut visible = ui_state.visible;
+
+    egui::Window::new("History")
+        .open(&mut visible)
+        .default_size([640.0, 360.0])
+        .show(ctx, |ui| {
+            if render_filters(ui, &mut ui_state, &rows.authors, own_identity) {
+                rows.dirty = true;
+            }
+
+            if rows.dirty {
+                rebuild_rows(&mut rows, &stdb, &ui_state, &selection);
+            }
+
+            ui.horizontal(|ui| {
+                ui.label(format!("{} log entries from the last", rows.rows.len()));
+                let window =
+                    ui.add(egui::DragValue::new(&mut ui_state.window_days_input).range(1..=3650));
+                // Each change resubscribes, so wait for the drag or typing to finish.
+                if window.drag_stopped() || window.lost_focus() {
+                    ui_state.window_days = ui_state.window_days_input;
+                }
+                ui.label("day(s)");
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    if ui.button("Prune").clicked() {
+                        prune(&stdb, &mut ui_state);
+                    }
+                    ui.label("day(s)");
+                    ui.add(egui::DragValue::new(&mut ui_state.prune_days).range(0..=3650));
+                    ui.label("Older than");
+                });
+            });
+            if let Some(error) = &ui_state.prune_error {
+                ui.colored_label(egui::Color32::from_rgb(230, 160, 60), error);
+            }
+            ui.separator();
+
+            let now = Timestamp::now();
+            ui.spacing_mut().item_spacing.y = 0.0;
+            egui::ScrollArea::vertical()
+                .auto_shrink([false, false])
+                .show_rows(ui, ROW_HEIGHT, rows.rows.len(), |ui, range| {
+                    for row in &rows.rows[range] {
+                        let selected = selection.contains(row.object_id);
+                        let response =
+                            render_row(ui, row, now, own_identity, selected).on_hover_text(
+                                format!("{} by {}\n{}", row.reducer, row.author, row.change),
+                            );
+
+                        // Deleted objects and world imports have nothing to select.
+                        if response.clicked()
+                            && stdb.db().world_object().id().find(&row.object_id).is_some()
+                        {
+                            selection.select_single(row.object_id);
+                        }
+                    }
+                });
+        });
+
+    ui_state.visible = visible;
+}
+
+/// The object and author filters. Returns whether either changed.
+fn render_filters(
+    ui: &mut egui::Ui,
+    ui_state: &mut HistoryUiState,
+    authors: &[Identity],
+    own_identity: Option<Identity>,
+) -> bool {
+    let mut changed = false;
+
+    ui.horizontal(|ui| {
+        let object_before = ui_state.object;
+        egui::ComboBox::from_id_salt("history_object_filter")
+            .selected_text(ui_state.object.label())
+            .show_ui(ui, |ui| {
+                for filter in [ObjectFilter::Any, ObjectFilter::Selected, ObjectFilter::Id] {
+                    ui.selectable_value(&mut ui_state.object, filter, filter.label());
+                }
+            });
+        changed |= ui_state.object != object_before;
+
+        if ui_state.object == ObjectFilter::Id {
+            let response = ui.add(
+                egui::TextEdit::singleline(&mut ui_state.object_id)
+                    .hint_text("id")
+                    .desired_width(72.0),
+            );
+            changed |= response.changed();
+        }
+
+        ui.separator();
+
+        let author_before = ui_state.author;
+        egui::ComboBox::from_id_salt("history_author_filter")
+            .selected_text(match ui_state.author {
+                Some(author) => author_label(author, own_identity),
+                None => "Anyone".to_string(),
+            })
+            .show_ui(ui, |ui| {
+                ui.selectable_value(&mut ui_state.author, None, "Anyone");
+                for author in authors {
+                    ui.selectable_value(
+                        &mut ui_state.author,
+                        Some(*author),
+                        author_label(*author, own_identity),
+                    );
+                }
+            });
+        changed |= ui_state.author != author_before;
+    });
+
+    changed
+}
+
+/// One list row as a single clickable rect with its columns painted on top.
+fn render_row(
+    ui: &mut egui::Ui,
+    row: &HistoryRow,
+    now: Timestamp,
+    own_identity: Option<Identity>,
+    selected: bool,
+) -> egui::Response {
+    let (rect, response) = ui.allocate_exact_size(
+        egui::vec2(ui.available_width(), ROW_HEIGHT),
+        egui::Sense::click(),
+    );
+
+    let visuals = ui.visuals();
+    if selected {
+        ui.painter()
+            .rect_filled(rect, 0.0, visuals.selection.bg_fill);
+    } else if response.hovered() {
+        ui.painter()
+            .rect_filled(rect, 0.0, visuals.widgets.hovered.weak_bg_fill);
+    }
+
+    let color = if selected {
+        visuals.selection.stroke.color
+    } else {
+        visuals.text_color()
+    };
+    let font = egui::FontId::monospace(12.0);
+    let y = rect.center().y;
+    let mut x = rect.left() + 4.0;
+
+    let object = match row.object_id {
+        0 => "world".to_string(),
+        id => id.to_string(),
+    };
+    for (text, width) in [
+        (age_label(now, row.at), WHEN_COLUMN_WIDTH),
+        (author_label(row.author, own_identity), AUTHOR_COLUMN_WIDTH),
+        (object, OBJECT_COLUMN_WIDTH),
+    ] {
+        let column = egui::Rect::from_x_y_ranges(x..=x + width - 4.0, rect.y_range());
+        ui.painter().with_clip_rect(column).text(
+            egui::pos2(x, y),
+            egui::Align2::LEFT_CENTER,
+            text,
+            font.clone(),
+            color,
+        );
+        x += width;
+    }
+
+    let change = egui::Rect::from_x_y_ranges(x..=rect.right() - 4.0, rect.y_range());
+    ui.painter().with_clip_rect(change).text(
+        egui::pos2(x, y),
+        egui::Align2::LEFT_CENTER,
+        &row.change,
+        font,
+        color,
+    );
+
+    response
+}
+
+fn rebuild_rows(
+    rows: &mut HistoryRows,
+    stdb: &SpacetimeDB,
+    ui_state: &HistoryUiState,
+    selection: &Selection,
+) {
+    let object_id = ui_state.object_id.trim().parse::<u64>().ok();
+    let matches_object = |id: u64| match ui_state.object {
+        ObjectFilter::Any => true,
+        ObjectFilter::Selected => selection.contains(id),
+        ObjectFilter::Id => object_id == Some(id),
+    };
+
+    let mut authors = Vec::new();
+    let mut entries: Vec<EditLog> = stdb
+        .db()
+        .edit_log()
+        .iter()
+        .inspect(|entry| authors.push(entry.author))
+        .filter(|entry| {
+            // World imports (object 0) touch every object, so they match any object filter.
+            (entry.object_id == 0 || matches_object(entry.object_id))
+                && ui_state.author.is_none_or(|author| author == entry.author)
+        })
+        .collect();
+    entries.sort_by_key(|entry| Reverse(entry.id));
+
+    authors.sort();
+    authors.dedup();
+    rows.authors = authors;
+
+    rows.rows = entries
+        .into_iter()
+        .map(|entry| HistoryRow {
+            object_id: entry.object_id,
+            at: entry.at,
+            author: entry.author,
+            change: describe_change(&entry),
+            reducer: entry.reducer,
+        })
+        .collect();
+
+    rows.dirty = false;
+}
+
+fn prune(stdb: &SpacetimeDB, ui_state: &mut HistoryUiState) {
+    let now = Timestamp::now().to_micros_since_unix_epoch();
+    let older_than = Timestamp::from_micros_since_unix_epoch(
+        now - i64::from(ui_state.prune_days) * MICROS_PER_DAY,
+    );
+
+    ui_state.prune_error = None;
+    if let Err(error) = stdb.reducers().prune_edit_log(older_than) {
+        ui_state.prune_error = Some(format!("Prune failed: {error}"));
+    }
+}
+
+/// What an entry changed, e.g. "translation (0.00, 1.00, 0.00) → (2.00, 1.00, 0.00)".
+fn describe_change(entry: &EditLog) -> String {
+    let (before, after) = match (&entry.before, &entry.after) {
+        (None, None) => return "replaced the world".to_string(),
+        (None, Some(_)) => return "created".to_string(),
+        (Some(_), None) => return "deleted".to_string(),
+        (Some(before), Some(after)) => (before, after),
+    };
+
+    let mut changes = Vec::new();
+    if before.asset != after.asset {
+        changes.push("asset".to_string());
+    }
+    if before.translation != after.translation {
+        changes.push(format!(
+            "translation {} → {}",
+            vec3_label(before.translation.clone().into()),
+            vec3_label(after.translation.clone().into())
+        ));
+    }
+    if before.rotation != after.rotation {
+        changes.push(format!(
+            "rotation {} → {}",
+            vec3_label(euler_degrees(before)),
+            vec3_label(euler_degrees(after))
+        ));
+    }
+    if before.scale != after.scale {
+        changes.push(format!(
+            "scale {} → {}",
+            vec3_label(before.scale.clone().into()),
+            vec3_label(after.scale.clone().into())
+        ));
+    }
+    if before.collision_shape != after.collision_shape {
+        changes.push("collision shape".to_string());
+    }
+
+    if changes.is_empty() {
+        "no change".to_string()
+    } else {
+        changes.join(", ")
+    }
+}
+
+/// Rotation as Euler angles in degrees (XYZ order), like the details panel shows it.
+fn euler_degrees(object: &WorldObject) -> Vec3 {
+    let (x, y, z) = Quat::from(object.rotation.clone()).to_euler(EulerRot::XYZ);
+    Vec3::new(x, y, z) * 180.0 / std::f32::consts::PI
+}
+
+fn vec3_label(v: Vec3) -> String {
+    format!("({:.2}, {:.2}, {:.2})", v.x, v.y, v.z)
+}
+
+fn author_label(author: Identity, own_identity: Option<Identity>) -> String {
+    let hex = author.to_abbreviated_hex().to_string();
+    if Some(author) == own_identity {
+        format!("{hex} (you)")
+    } else {
+        hex
+    }
+}
+
+/// How long ago `at` was, e.g. "42s ago" or "3d ago".
+fn age_label(now: Timestamp, at: Timestamp) -> String {
+    let seconds = now
+        .duration_since(at)
+        .map(|age| age.as_secs())
+        .unwrap_or_default();
+
+    match seconds {
+        0..60 => format!("{seconds}s ago"),
+        60..3600 => format!("{}m ago", seconds / 60),
+        3600..86400 => format!("{}h ago", seconds / 3600),
+        _ => format!("{}d ago", seconds / 86400),
+    }
+}
@@ -0,0 +1,313 @@
+//! Collision section of the details panel: the selected object's `collision_shape`.
+//!
+//! Picking a kind replaces the shape with a default-sized one, whose dimensions are then edited
+//! like the transform fields. Heightfields and convex hulls are generated from data rather than
+//! typed in, so they're only summarized here; picking another kind still replaces them.
+//!
+//! Objects rendered from a glTF file can also get a convex hull of their meshes: it's
+//! generated as a preview (see `convex_hull`), then applied or discarded.
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+
+use super::{AXIS_LABELS, FieldEdit, MIXED_EPSILON, number_field};
+use crate::{
+    convex_hull::GeneratedHull,
+    module_bindings::{Ball, Capsule, CollisionShape, Cuboid, Segment},
+};
+
+/// Most vertices a generated hull can be limited to from the panel.
+const MAX_HULL_VERTICES: usize = 1024;
+
+/// One number field of a shape.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(super) enum CollisionField {
+    HalfExtent(usize),
+    Radius,
+    EndpointA(usize),
+    EndpointB(usize),
+}
+
+/// Options for generating a convex hull.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct HullSettings {
+    limit_vertices: bool,
+    max_vertices: usize,
+}
+
+impl Default for HullSettings {
+    fn default() -> Self {
+        Self {
+            limit_vertices: true,
+            max_vertices: 64,
+        }
+    }
+}
+
+/// What the convex hull controls asked for.
+pub(super) enum HullAction {
+    Generate { max_vertices: Option<usize> },
+    Apply(CollisionShape),
+    Discard,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ShapeKind {
+    None,
+    Cuboid,
+    Ball,
+    Capsule,
+    Heightfield,
+    ConvexHull,
+}
+
+impl ShapeKind {
+    /// The kinds that can be picked in the panel.
+    const EDITABLE: [ShapeKind; 4] = [
+        ShapeKind::None,
+        ShapeKind::Cuboid,
+        ShapeKind::Ball,
+        ShapeKind::Capsule,
+    ];
+
+    fn of(shape: &CollisionShape) -> Self {
+        match shape {
+            CollisionShape::None => ShapeKind::None,
+            CollisionShape::Cuboid(_) => ShapeKind::Cuboid,
+            CollisionShape::Ball(_) => ShapeKind::Ball,
+            CollisionShape::Capsule(_) => ShapeKind::Capsule,
+            CollisionShape::Heightfield(_) => ShapeKind::Heightfield,
+            CollisionShape::ConvexHull(_) => ShapeKind::ConvexHull,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            ShapeKind::None => "None",
+            ShapeKind::Cuboid => "Cuboid",
+            ShapeKind::Ball => "Ball",
+            ShapeKind::Capsule => "Capsule",
+            ShapeKind::Heightfield => "Heightfield",
+            ShapeKind::ConvexHull => "Convex hull",
+        }
+    }
+
+    /// A unit-sized shape of this kind, for when it's first picked.
+    fn default_shape(self) -> Option<CollisionShape> {
+        match self {
+            ShapeKind::None => Some(CollisionShape::None),
+            ShapeKind::Cuboid => Some(CollisionShape::Cuboid(Cuboid {
+                half_extents: Vec3::splat(0.5).into(),
+            })),
+            ShapeKind::Ball => Some(CollisionShape::Ball(Ball { radius: 0.5 })),
+            ShapeKind::Capsule => Some(CollisionShape::Capsule(Capsule {
+                segment: Segment {
+                    a: Vec3::new(0.0, -0.25, 0.0).into(),
+                    b: Vec3::new(0.0, 0.25, 0.0).into(),
+                },
+                radius: 0.25,
+            })),
+            ShapeKind::Heightfield | ShapeKind::ConvexHull => None,
+        }
+    }
+}
+
+/// The shape kind picker and the fields of the current shape. Returns the new shape when an
+/// edit is committed.
+pub(super) fn render(
+    ui: &mut egui::Ui,
+    shape: &CollisionShape,
+    editing: &mut Option<FieldEdit<CollisionField>>,
+) -> Option<CollisionShape> {
+    let mut committed = None;
+
+    let current = ShapeKind::of(shape);
+    ui.horizontal(|ui| {
+        ui.label("Shape:");
+        egui::ComboBox::from_id_salt("details_collision_kind")
+            .selected_text(current.label())
+            .show_ui(ui, |ui| {
+                for kind in ShapeKind::EDITABLE {
+                    if ui.selectable_label(kind == current, kind.label()).clicked()
+                        && kind != current
+                    {
+                        committed = kind.default_shape();
+                    }
+                }
+            });
+    });
+
+    match shape {
+        CollisionShape::None => {}
+        CollisionShape::Cuboid(cuboid) => {
+            shape_grid(ui, |ui| {
+                let half_extents: Vec3 = cuboid.half_extents.clone().into();
+                if let Some((axis, typed)) = vec3_row(
+                    ui,
+                    "Half extents",
+                    half_extents,
+                    CollisionField::HalfExtent,
+                    editing,
+                ) {
+                    let mut half_extents = half_extents;
+                    half_extents[axis] = typed;
+                    if typed > 0.0 {
+                        committed = Some(CollisionShape::Cuboid(Cuboid {
+                            half_extents: half_extents.into(),
+                        }));
+                    }
+                }
+            });
+        }
+        CollisionShape::Ball(ball) => {
+            shape_grid(ui, |ui| {
+                if let Some(radius) = radius_row(ui, ball.radius, editing) {
+                    committed = Some(CollisionShape::Ball(Ball { radius }));
+                }
+            });
+        }
+        CollisionShape::Capsule(capsule) => {
+            shape_grid(ui, |ui| {
+                let a: Vec3 = capsule.segment.a.clone().into();
+                let b: Vec3 = capsule.segment.b.clone().into();
+                let mut edited = capsule.clone();
+
+                if let Some((axis, typed)) =
+                    vec3_row(ui, "Endpoint A", a, CollisionField::EndpointA, editing)
+                {
+                    let mut a = a;
+                    a[axis] = typed;
+                    edited.segment.a = a.into();
+                    committed = Some(CollisionShape::Capsule(edited.clone()));
+                }
+                if let Some((axis, typed)) =
+                    vec3_row(ui, "Endpoint B", b, CollisionField::EndpointB, editing)
+                {
+                    let mut b = b;
+                    b[axis] = typed;
+                    edited.segment.b = b.into();
+                    committed = Some(CollisionShape::Capsule(edited.clone()));
+                }
+                if let Some(radius) = radius_row(ui, capsule.radius, editing) {
+                    edited.radius = radius;
+                    committed = Some(CollisionShape::Capsule(edited));
+                }
+            });
+        }
+        CollisionShape::Heightfield(heightfield) => {
+            ui.weak(format!(
+                "{} × {} samples",
+                heightfield.width, heightfield.height
+            ));
+        }
+        CollisionShape::ConvexHull(hull) => {
+            ui.weak(format!(
+                "{} points, {} triangles",
+                hull.points.len(),
+                hull.indices.len()
+            ));
+        }
+    }
+
+    committed
+}
+
+/// A grid laid out like the transform grid, with an axis header.
+fn shape_grid(ui: &mut egui::Ui, add_rows: impl FnOnce(&mut egui::Ui)) {
+    egui::Grid::new("details_collision")
+        .num_columns(4)
+        .spacing([6.0, 4.0])
+        .show(ui, |ui| {
+            ui.label("");
+            for label in AXIS_LABELS {
+                ui.label(label);
+            }
+            ui.end_row();
+
+            add_rows(ui);
+        });
+}
+
+/// A labelled row of three number fields. Returns the axis and value of a committed edit that
+/// changes the value.
+fn vec3_row(
+    ui: &mut egui::Ui,
+    label: &str,
+    value: Vec3,
+    field: fn(usize) -> CollisionField,
+    editing: &mut Option<FieldEdit<CollisionField>>,
+) -> Option<(usize, f32)> {
+    let mut committed = None;
+    ui.label(label);
+    for axis in 0..3 {
+        if let Some(typed) = number_field(ui, field(axis), Some(value[axis]), editing)
+            && (typed - value[axis]).abs() > MIXED_EPSILON
+        {
+            committed = Some((axis, typed));
+        }
+    }
+    ui.end_row();
+    committed
+}
+
+/// A radius row. Returns the typed radius if it's a usable one and differs from `radius`.
+fn radius_row(
+    ui: &mut egui::Ui,
+    radius: f32,
+    editing: &mut Option<FieldEdit<CollisionField>>,
+) -> Option<f32> {
+    ui.label("Radius");
+    let typed = number_field(ui, CollisionField::Radius, Some(radius), editing);
+    ui.end_row();
+    typed.filter(|typed| *typed > 0.0 && (typed - radius).abs() > MIXED_EPSILON)
+}
+
+/// The vertex budget, the generate button and the pending hull, if any.
+pub(super) fn render_hull_generation(
+    ui: &mut egui::Ui,
+    settings: &mut HullSettings,
+    preview: Option<&Result<GeneratedHull, String>>,
+) -> Option<HullAction> {
+    let mut action = None;
+
+    ui.add_space(6.0);
+    ui.horizontal(|ui| {
+        ui.checkbox(&mut settings.limit_vertices, "Max vertices");
+        ui.add_enabled(
+            settings.limit_vertices,
+            egui::DragValue::new(&mut settings.max_vertices).range(4..=MAX_HULL_VERTICES),
+        );
+    });
+    if ui
+        .button("Generate convex hull")
+        .on_hover_text("Wrap the object's loaded meshes in a convex hull collider")
+        .clicked()
+    {
+        action = Some(HullAction::Generate {
+            max_vertices: settings.limit_vertices.then_some(settings.max_vertices),
+        });
+    }
+
+    match preview {
+        None => {}
+        Some(Ok(hull)) => {
+            ui.weak(format!(
+                "Preview: {} points, {} triangles",
+                hull.point_count, hull.triangle_count
+            ));
+            ui.horizontal(|ui| {
+                if ui.button("Apply").clicked() {
+                    action = Some(HullAction::Apply(hull.shape.clone()));
+                }
+                if ui.button("Discard").clicked() {
+                    action = Some(HullAction::Discard);
+                }
+            });
+        }
+        Some(Err(err)) => {
+            ui.colored_label(egui::Color32::from_rgb(230, 160, 60), err);
+        }
+    }
+
+    action
+}
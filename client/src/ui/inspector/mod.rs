@@ -0,0 +1,477 @@
+//! Details panel: the selected objects' id, asset and transform as editable numbers, and the
+//! collision shape of a single selected object (see `collision`), which for glTF-backed
+//! objects can be generated as a convex hull of their meshes.
+//!
+//! Values come from the subscription cache, not the rendered entities, so the panel always
+//! shows what the DB has. Each field commits on Enter or when it loses focus (Escape discards)
+//! and sends `move_object`, `rotate_object` or `scale_object` for every selected object. A field
+//! whose text wasn't changed commits nothing, since the shown value is rounded.
+//!
+//! With several objects selected, fields whose values differ show as "Mixed". Edits then
+//! either set the component on every object (Absolute) or add to it (Relative).
+//!
+//! While the panel shows objects it holds locks on them, so nobody else edits them
+//! meanwhile. Objects another editor has locked show who holds them and can't be edited.
+
+mod collision;
+
+use std::hash::Hash;
+
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, EguiPrimaryContextPass, egui};
+
+use crate::{
+    convex_hull::{GenerateConvexHull, HullPreview},
+    locks::{LockRequests, editor_name, first_locked_by_other},
+    module_bindings::{AssetKind, WorldObject, WorldObjectTableAccess},
+    pending_ops::EditorOps,
+    selection::Selection,
+    snapshot::SyncState,
+    spacetimedb::SpacetimeDB,
+    ui::{asset_label, transform_tools::TransformTool},
+};
+
+/// Values closer than this count as equal when deciding whether a field is mixed.
+const MIXED_EPSILON: f32 = 1e-4;
+
+const AXIS_LABELS: [&str; 3] = ["X", "Y", "Z"];
+
+#[derive(Resource)]
+pub struct InspectorUiState {
+    pub visible: bool,
+    mode: EditMode,
+    /// Text of the field being typed into, if any.
+    editing: Option<FieldEdit<Field>>,
+    collision_editing: Option<FieldEdit<collision::CollisionField>>,
+    hull_settings: collision::HullSettings,
+}
+
+impl Default for InspectorUiState {
+    fn default() -> Self {
+        Self {
+            visible: true,
+            mode: EditMode::Absolute,
+            editing: None,
+            collision_editing: None,
+            hull_settings: collision::HullSettings::default(),
+        }
+    }
+}
+
+/// How an edit applies to a multi-selection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EditMode {
+    /// Set the component to the typed value on every object.
+    Absolute,
+    /// Add the typed value to the component of every object.
+    Relative,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Component {
+    Translation,
+    /// Euler angles in degrees (XYZ order).
+    Rotation,
+    Scale,
+}
+
+impl Component {
+    fn label(self) -> &'static str {
+        match self {
+            Component::Translation => "Translation",
+            Component::Rotation => "Rotation",
+            Component::Scale => "Scale",
+        }
+    }
+
+    /// The component of `row` as three editable numbers.
+    fn values(self, row: &WorldObject) -> Vec3 {
+        match self {
+            Component::Translation => row.translation.clone().into(),
+            Component::Rotation => {
+                let (x, y, z) = Quat::from(row.rotation.clone()).to_euler(EulerRot::XYZ);
+                Vec3::new(x, y, z) * 180.0 / std::f32::consts::PI
+            }
+            Component::Scale => row.scale.clone().into(),
+        }
+    }
+}
+
+/// One number field: a component and an axis index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Field {
+    component: Component,
+    axis: usize,
+}
+
+/// The text typed into a number field that hasn't been committed yet.
+#[derive(Debug, Clone)]
+struct FieldEdit<F> {
+    field: F,
+    text: String,
+    /// The text shown when the field was focused.
+    initial: String,
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<InspectorUiState>();
+    // Render panels in the egui pass schedule so the pass state is initialized.
+    app.add_systems(EguiPrimaryContextPass, render);
+}
+
+#[allow(clippy::too_many_arguments)]
+fn render(
+    mut contexts: EguiContexts,
+    mut ui_state: ResMut<InspectorUiState>,
+    selection: Res<Selection>,
+    stdb: SpacetimeDB,
+    sync: Res<SyncState>,
+    tool: Res<TransformTool>,
+    mut locks: ResMut<LockRequests>,
+    mut hull_preview: ResMut<HullPreview>,
+    mut hull_requests: MessageWriter<GenerateConvexHull>,
+    mut ops: EditorOps,
+) {
+    // The locks go when the selection changes or the panel closes; `sync_locks` renews them
+    // until then.
+    let shown = if ui_state.visible {
+        selection.ids()
+    } else {
+        &[]
+    };
+    if locks.details != shown {
+        locks.details = shown.to_vec();
+    }
+
+    if !ui_state.visible {
+        return;
+    }
+
+    let ctx = contexts.ctx_mut().expect("to get primary egui context");
+
+    let table = stdb.db().world_object();
+    let rows: Vec<WorldObject> = selection
+        .ids()
+        .iter()
+        .filter_map(|id| table.id().find(id))
+        .collect();
+
+    let mut committed = None;
+    let mut collision_committed = None;
+    let mut hull_action = None;
+
+    egui::SidePanel::right("details_panel")
+        .resizable(true)
+        .default_width(300.0)
+        .min_width(220.0)
+        .show(ctx, |ui| {
+            ui.heading("Details");
+            ui.separator();
+
+            if rows.is_empty() {
+                ui.label("Nothing selected.");
+                return;
+            }
+
+            render_identity(ui, &rows);
+            let locked = first_locked_by_other(&stdb, selection.ids());
+            if let Some((id, owner)) = locked {
+                let label = match rows.len() {
+                    1 => format!("🔒 Locked by {}", editor_name(&stdb, owner)),
+                    _ => format!(
+                        "🔒 Object {} is locked by {}",
+                        id,
+                        editor_name(&stdb, owner)
+                    ),
+                };
+                ui.colored_label(egui::Color32::from_rgb(230, 160, 60), label);
+            }
+            ui.add_space(6.0);
+
+            if rows.len() > 1 {
+                ui.horizontal(|ui| {
+                    ui.label("Edits:");
+                    ui.selectable_value(&mut ui_state.mode, EditMode::Absolute, "Absolute")
+                        .on_hover_text("Set the typed value on every selected object");
+                    ui.selectable_value(&mut ui_state.mode, EditMode::Relative, "Relative")
+                        .on_hover_text("Add the typed value to every selected object");
+                });
+                ui.add_space(6.0);
+            }
+
+            // Editing mid-drag would fight the drag, and edits are refused while out of sync
+            // or while someone else holds a lock.
+            let editable = sync.allows_edits() && !tool.is_active && locked.is_none();
+            let relative = rows.len() > 1 && ui_state.mode == EditMode::Relative;
+
+            ui.add_enabled_ui(editable, |ui| {
+                egui::Grid::new("details_transform")
+                    .num_columns(4)
+                    .spacing([6.0, 4.0])
+                    .show(ui, |ui| {
+                        ui.label("");
+                        for label in AXIS_LABELS {
+                            ui.label(label);
+                        }
+                        ui.end_row();
+
+                        for component in [
+                            Component::Translation,
+                            Component::Rotation,
+                            Component::Scale,
+                        ] {
+                            ui.label(component.label());
+                            for axis in 0..3 {
+                                let field = Field { component, axis };
+                                let value = if relative {
+                                    Some(0.0)
+                                } else {
+                                    common_value(&rows, field)
+                                };
+                                if let Some(typed) =
+                                    number_field(ui, field, value, &mut ui_state.editing)
+                                {
+                                    committed = Some((field, typed));
+                                }
+                            }
+                            ui.end_row();
+                        }
+                    });
+
+                if let [row] = rows.as_slice() {
+                    ui.add_space(10.0);
+                    ui.strong("Collision");
+                    ui.separator();
+                    if let Some(shape) =
+                        collision::render(ui, &row.collision_shape, &mut ui_state.collision_editing)
+                    {
+                        collision_committed = Some((row.id, shape));
+                    }
+
+                    if let AssetKind::Path(_) = row.asset {
+                        let preview = hull_preview
+                            .0
+                            .as_ref()
+                            .filter(|pending| pending.id == row.id)
+                            .map(|pending| &pending.result);
+                        if let Some(action) = collision::render_hull_generation(
+                            ui,
+                            &mut ui_state.hull_settings,
+                            preview,
+                        ) {
+                            hull_action = Some((row.id, action));
+                        }
+                    }
+                }
+            });
+        });
+
+    if let Some((id, shape)) = collision_committed {
+        ops.set_collision_shape(id, shape);
+    }
+
+    match hull_action {
+        None => {}
+        Some((id, collision::HullAction::Generate { max_vertices })) => {
+            hull_requests.write(GenerateConvexHull { id, max_vertices });
+        }
+        Some((id, collision::HullAction::Apply(shape))) => {
+            ops.set_collision_shape(id, shape);
+            hull_preview.0 = None;
+        }
+        Some((_, collision::HullAction::Discard)) => hull_preview.0 = None,
+    }
+
+    if let Some((field, typed)) = committed {
+        let mode = if rows.len() > 1 {
+            ui_state.mode
+        } else {
+            EditMode::Absolute
+        };
+        apply_edit(&mut ops, &rows, field, typed, mode);
+    }
+}
+
+fn render_identity(ui: &mut egui::Ui, rows: &[WorldObject]) {
+    egui::Grid::new("details_identity")
+        .num_columns(2)
+        .show(ui, |ui| {
+            match rows {
+                [row] => {
+                    ui.label("ID:");
+                    ui.monospace(row.id.to_string());
+                }
+                _ => {
+                    ui.label("Selected:");
+                    ui.label(format!("{} objects", rows.len()));
+                }
+            }
+            ui.end_row();
+
+            ui.label("Asset:");
+            let asset = &rows[0].asset;
+            if rows.iter().all(|row| row.asset == *asset) {
+                ui.monospace(asset_label(asset));
+            } else {
+                ui.weak("Mixed");
+            }
+            ui.end_row();
+        });
+}
+
+/// The field's value if every row has (about) the same one, `None` if they're mixed.
+fn common_value(rows: &[WorldObject], field: Field) -> Option<f32> {
+    let first = field.component.values(&rows[0])[field.axis];
+    rows.iter()
+        .all(|row| (field.component.values(row)[field.axis] - first).abs() <= MIXED_EPSILON)
+        .then_some(first)
+}
+
+/// A text field for one number. Returns the typed value when the edit is committed.
+fn number_field<F: Copy + PartialEq + Hash>(
+    ui: &mut egui::Ui,
+    field: F,
+    value: Option<f32>,
+    editing: &mut Option<FieldEdit<F>>,
+) -> Option<f32> {
+    let (mut text, initial) = match editing {
+        Some(edit) if edit.field == field => (
+            std::mem::take(&mut edit.text),
+            std::mem::take(&mut edit.initial),
+        ),
+        _ => {
+            let shown = value.map(|value| format!("{value:.3}")).unwrap_or_default();
+            (shown.clone(), shown)
+        }
+    };
+
+    let response = ui.add(
+        egui::TextEdit::singleline(&mut text)
+            .id(egui::Id::new(("details_field", field)))
+            .hint_text("Mixed")
+            .desired_width(64.0),
+    );
+
+    if response.has_focus() {
+        *editing = Some(FieldEdit {
+            field,
+            text,
+            initial,
+        });
+        return None;
+    }
+
+    if !response.lost_focus() {
+        return None;
+    }
+
+    *editing = None;
+    // Committing untouched text would write back the rounded value.
+    if text == initial || ui.input(|input| input.key_pressed(egui::Key::Escape)) {
+        return None;
+    }
+    text.trim()
+        .parse::<f32>()
+        .ok()
+        .filter(|typed| typed.is_finite())
+}
+
+fn apply_edit(ops: &mut EditorOps, rows: &[WorldObject], field: Field, typed: f32, mode: EditMode) {
+    for row in rows {
+        let mut values = field.component.values(row);
+        let before = values[field.axis];
+        values[field.axis] = match mode {
+            EditMode::Absolute => typed,
+            EditMode::Relative => before + typed,
+        };
+        if (values[field.axis] - before).abs() <= MIXED_EPSILON {
+            continue;
+        }
+
+        match field.component {
+            Component::Translation => ops.move_object(row.id, values.into()),
+            Component::Rotation => {
+                let radians = values * std::f32::consts::PI / 180.0;
+                let rotation = Quat::from_euler(EulerRot::XYZ, radians.x, radians.y, radians.z);
+                ops.rotate_object(row.id, rotation.into());
+            }
+            Component::Scale => ops.scale_object(row.id, values.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module_bindings::{Ball, CollisionShape};
+
+    const FIELD: Field = Field {
+        component: Component::Translation,
+        axis: 0,
+    };
+
+    /// Run one egui frame and return what `add` returned.
+    fn frame<R>(ctx: &egui::Context, mut add: impl FnMut(&mut egui::Ui) -> R) -> R {
+        let mut result = None;
+        let _ = ctx.run(egui::RawInput::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| result = Some(add(ui)));
+        });
+        result.expect("the panel to run")
+    }
+
+    /// Focus `field` for one frame, let `type_text` edit it, then blur it and return what
+    /// `show` committed.
+    fn focus_then_blur<F: Copy + Hash, R: PartialEq + std::fmt::Debug>(
+        field: F,
+        mut show: impl FnMut(&mut egui::Ui, &mut Option<FieldEdit<F>>) -> Option<R>,
+        type_text: impl FnOnce(&mut String),
+    ) -> Option<R> {
+        let ctx = egui::Context::default();
+        let id = egui::Id::new(("details_field", field));
+        let mut editing = None;
+
+        let focused = frame(&ctx, |ui| {
+            ui.memory_mut(|memory| memory.request_focus(id));
+            show(ui, &mut editing)
+        });
+        assert_eq!(focused, None);
+        type_text(&mut editing.as_mut().expect("the field to be focused").text);
+
+        frame(&ctx, |ui| {
+            ui.memory_mut(|memory| memory.surrender_focus(id));
+            show(ui, &mut editing)
+        })
+    }
+
+    fn show_field(ui: &mut egui::Ui, editing: &mut Option<FieldEdit<Field>>) -> Option<f32> {
+        number_field(ui, FIELD, Some(1.234_567), editing)
+    }
+
+    #[test]
+    fn focus_and_blur_commits_nothing() {
+        assert_eq!(focus_then_blur(FIELD, show_field, |_| {}), None);
+    }
+
+    #[test]
+    fn changed_text_commits_on_blur() {
+        let committed = focus_then_blur(FIELD, show_field, |text| *text = "2.5".into());
+        assert_eq!(committed, Some(2.5));
+    }
+
+    #[test]
+    fn collision_field_commits_only_changed_values() {
+        let shape = CollisionShape::Ball(Ball { radius: 0.123_456 });
+        let show = |ui: &mut egui::Ui, editing: &mut _| collision::render(ui, &shape, editing);
+        let radius = collision::CollisionField::Radius;
+
+        assert_eq!(focus_then_blur(radius, show, |_| {}), None);
+        assert_eq!(
+            focus_then_blur(radius, show, |text| *text = "0.123456".into()),
+            None
+        );
+        assert_eq!(
+            focus_then_blur(radius, show, |text| *text = "0.5".into()),
+            Some(CollisionShape::Ball(Ball { radius: 0.5 }))
+        );
+    }
+}
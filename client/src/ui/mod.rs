@@ -1,5 +1,9 @@
 mod app_bar;
 mod asset_browser;
+mod history;
+mod inspector;
+mod out_of_sync;
+mod outliner;
 mod performance;
 pub mod transform_tools;
 
@@ -13,6 +17,8 @@ use bevy::{
 };
 use bevy_egui::{EguiGlobalSettings, EguiPlugin, PrimaryEguiContext};
 
+use crate::module_bindings::{AssetKind, PrimitiveShape};
+
 pub fn plugin(app: &mut App) {
     // Register egui once, from a central place.
     // All performance-related UI is owned by `ui/performance`.
@@ -22,6 +28,10 @@ pub fn plugin(app: &mut App) {
         app_bar::plugin,
         transform_tools::plugin,
         asset_browser::plugin,
+        inspector::plugin,
+        outliner::plugin,
+        history::plugin,
+        out_of_sync::plugin,
     ));
     app.add_systems(Startup, setup);
 }
@@ -47,3 +57,13 @@ fn setup(mut commands: Commands, mut egui_global_settings: ResMut<EguiGlobalSett
         },
     ));
 }
+
+/// Short display name for a world object's asset, shared by the outliner and details panels.
+fn asset_label(asset: &AssetKind) -> String {
+    match asset {
+        AssetKind::Path(path) => path.clone(),
+        AssetKind::PrimitiveShape(PrimitiveShape::Cuboid(_)) => "Primitive: Cuboid".to_string(),
+        AssetKind::PrimitiveShape(PrimitiveShape::Ball(_)) => "Primitive: Ball".to_string(),
+        AssetKind::PrimitiveShape(PrimitiveShape::Capsule(_)) => "Primitive: Capsule".to_string(),
+    }
+}
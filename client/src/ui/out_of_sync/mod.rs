@@ -0,0 +1,144 @@
+//! Modal shown while the world is out of sync with the local snapshot backup.
+//!
+//! Both ways out overwrite something, so each button says exactly what will be lost.
+
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, EguiPrimaryContextPass, egui};
+
+use crate::snapshot::{ResolveSync, RestoreProgress, SnapshotStore, SyncState};
+
+const WARNING_COLOR: egui::Color32 = egui::Color32::from_rgb(230, 160, 60);
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(EguiPrimaryContextPass, render);
+}
+
+fn render(
+    mut contexts: EguiContexts,
+    state: Res<SyncState>,
+    store: Res<SnapshotStore>,
+    mut resolve: MessageWriter<ResolveSync>,
+) {
+    if !matches!(*state, SyncState::OutOfSync(_) | SyncState::Restoring(_)) {
+        return;
+    }
+
+    let ctx = contexts.ctx_mut().expect("to get primary egui context");
+
+    // Dismissing isn't an option: editing stays blocked until one side is chosen.
+    egui::Modal::new(egui::Id::new("out_of_sync_modal")).show(ctx, |ui| {
+        ui.set_width(480.0);
+        ui.heading("World out of sync");
+        ui.add_space(6.0);
+
+        let mismatch = match &*state {
+            SyncState::OutOfSync(mismatch) => mismatch,
+            SyncState::Restoring(progress) => {
+                render_restore_progress(ui, progress);
+                return;
+            }
+            _ => return,
+        };
+
+        ui.label(
+            "The local snapshot backup and the SpacetimeDB world_object table don't match. \
+             This usually means the database was wiped or edited without this editor. \
+             World editing is disabled until you choose which one to keep.",
+        );
+        ui.add_space(6.0);
+
+        egui::Grid::new("out_of_sync_summary")
+            .num_columns(2)
+            .show(ui, |ui| {
+                ui.label("Snapshot:");
+                match mismatch.snapshot {
+                    Some(snapshot) => ui.label(format!("{} object(s)", snapshot.rows)),
+                    None => ui.label("unreadable"),
+                };
+                ui.end_row();
+
+                ui.label("Database:");
+                ui.label(format!("{} object(s)", mismatch.db.rows));
+                ui.end_row();
+
+                if mismatch.missing > 0 {
+                    ui.label("Missing from DB:");
+                    ui.label(format!("{} object(s)", mismatch.missing));
+                    ui.end_row();
+                }
+
+                if mismatch.changed > 0 {
+                    ui.label("Changed in DB:");
+                    ui.label(format!("{} object(s)", mismatch.changed));
+                    ui.end_row();
+                }
+
+                ui.label("Snapshot file:");
+                ui.monospace(store.path.display().to_string());
+                ui.end_row();
+            });
+
+        if let Some(error) = &mismatch.error {
+            ui.add_space(6.0);
+            ui.colored_label(egui::Color32::RED, error);
+        }
+
+        ui.add_space(8.0);
+        ui.separator();
+
+        let snapshot_rows = mismatch.snapshot.map(|snapshot| snapshot.rows);
+        let accept_snapshot = ui.add_enabled(
+            snapshot_rows.is_some(),
+            egui::Button::new("Accept snapshot (overwrite DB)"),
+        );
+        ui.colored_label(
+            WARNING_COLOR,
+            format!(
+                "Destructive: deletes all {} object(s) in the database and replaces them with \
+                 the {} object(s) from the snapshot. Changes made in the database since the \
+                 snapshot was written are lost.",
+                mismatch.db.rows,
+                snapshot_rows.unwrap_or(0)
+            ),
+        );
+        if accept_snapshot.clicked() {
+            resolve.write(ResolveSync::AcceptSnapshot);
+        }
+
+        ui.add_space(8.0);
+
+        if ui.button("Accept DB (overwrite snapshot)").clicked() {
+            resolve.write(ResolveSync::AcceptDb);
+        }
+        ui.colored_label(
+            WARNING_COLOR,
+            format!(
+                "Destructive: replaces the snapshot backup with the {} object(s) currently in \
+                 the database. Only the previous snapshot is kept, as world_snapshot.ron.prev.",
+                mismatch.db.rows
+            ),
+        );
+    });
+}
+
+fn render_restore_progress(ui: &mut egui::Ui, progress: &RestoreProgress) {
+    ui.horizontal(|ui| {
+        ui.spinner();
+        ui.label("Restoring the snapshot into the database...");
+    });
+
+    // Chunks only stage rows; nothing in the database changes until the final commit.
+    let fraction = if progress.chunks_sent == 0 {
+        1.0
+    } else {
+        progress.chunks_staged as f32 / progress.chunks_sent as f32
+    };
+    ui.add(egui::ProgressBar::new(fraction).text(format!(
+        "{} / {} chunk(s) staged",
+        progress.chunks_staged, progress.chunks_sent
+    )));
+
+    if let Some(error) = &progress.error {
+        ui.colored_label(egui::Color32::RED, error);
+    }
+}
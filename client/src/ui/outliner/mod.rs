@@ -0,0 +1,363 @@
+//! Outliner: every subscribed `world_object` row as a filterable, sortable list.
+//!
+//! Worlds can hold tens of thousands of objects, so the list is virtualized: only the rows
+//! scrolled into view are laid out, each as a single clickable rect painted by hand. The
+//! filtered and sorted rows are cached and only rebuilt when the table, the filter or the
+//! sort order changes.
+//!
+//! Clicking a row selects the object (Shift adds, Ctrl toggles), the list highlights whatever
+//! is selected in the viewport, and double-clicking a row moves the camera to the object.
+//! Objects another editor has locked are marked, with the holder in the row's tooltip.
+
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, EguiPrimaryContextPass, egui};
+use bevy_spacetimedb::{ReadDeleteMessage, ReadInsertMessage, ReadUpdateMessage};
+use spacetimedb_sdk::Table;
+
+use crate::{
+    flycam::FocusCamera,
+    locks::{editor_name, locked_by_other},
+    module_bindings::{CollisionShape, WorldObject, WorldObjectTableAccess},
+    selection::Selection,
+    spacetimedb::SpacetimeDB,
+    ui::asset_label,
+};
+
+const ROW_HEIGHT: f32 = 18.0;
+const ID_COLUMN_WIDTH: f32 = 72.0;
+const COLLISION_COLUMN_WIDTH: f32 = 84.0;
+
+/// How far the camera backs off per unit of the object's largest scale when focusing it.
+const FOCUS_DISTANCE_PER_SCALE: f32 = 5.0;
+const MIN_FOCUS_DISTANCE: f32 = 2.0;
+
+#[derive(Resource)]
+pub struct OutlinerUiState {
+    pub visible: bool,
+    filter: String,
+    sort: SortColumn,
+    descending: bool,
+}
+
+impl Default for OutlinerUiState {
+    fn default() -> Self {
+        Self {
+            visible: true,
+            filter: String::new(),
+            sort: SortColumn::Id,
+            descending: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SortColumn {
+    Id,
+    Asset,
+    Collision,
+}
+
+impl SortColumn {
+    fn label(self) -> &'static str {
+        match self {
+            SortColumn::Id => "ID",
+            SortColumn::Asset => "Asset",
+            SortColumn::Collision => "Collision",
+        }
+    }
+}
+
+/// One listed row, with its labels formatted once per rebuild.
+struct OutlinerRow {
+    id: u64,
+    asset: String,
+    collision: &'static str,
+}
+
+/// The rows currently listed, filtered and sorted.
+#[derive(Resource, Default)]
+struct OutlinerRows {
+    rows: Vec<OutlinerRow>,
+    /// Set when the table, filter or sort changed since the last rebuild.
+    dirty: bool,
+}
+
+/// The row selected from the outliner itself; the list doesn't scroll to those.
+#[derive(Resource, Default)]
+struct ClickedRow(Option<u64>);
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<OutlinerUiState>();
+    app.insert_resource(OutlinerRows {
+        rows: Vec::new(),
+        dirty: true,
+    });
+    app.init_resource::<ClickedRow>();
+    app.add_systems(Update, mark_dirty_on_table_change);
+    // Render panels in the egui pass schedule so the pass state is initialized.
+    app.add_systems(EguiPrimaryContextPass, render);
+}
+
+fn mark_dirty_on_table_change(
+    mut inserted: ReadInsertMessage<WorldObject>,
+    mut updated: ReadUpdateMessage<WorldObject>,
+    mut deleted: ReadDeleteMessage<WorldObject>,
+    mut rows: ResMut<OutlinerRows>,
+) {
+    // Drain every reader; a single change is enough to rebuild.
+    let changed = inserted.read().count() + updated.read().count() + deleted.read().count();
+    if changed > 0 {
+        rows.dirty = true;
+    }
+}
+
+fn render(
+    mut contexts: EguiContexts,
+    mut ui_state: ResMut<OutlinerUiState>,
+    mut rows: ResMut<OutlinerRows>,
+    mut clicked_row: ResMut<ClickedRow>,
+    mut selection: ResMut<Selection>,
+    stdb: SpacetimeDB,
+    mut focus: MessageWriter<FocusCamera>,
+) {
+    if !ui_state.visible {
+        return;
+    }
+
+    let ctx = contexts.ctx_mut().expect("to get primary egui context");
+
+    egui::SidePanel::left("outliner_panel")
+        .resizable(true)
+        .default_width(320.0)
+        .min_width(220.0)
+        .show(ctx, |ui| {
+            ui.heading("Outliner");
+            ui.separator();
+
+            ui.horizontal(|ui| {
+                ui.label("Filter:");
+                let response = ui.add(
+                    egui::TextEdit::singleline(&mut ui_state.filter)
+                        .hint_text("id, asset or collision")
+                        .desired_width(f32::INFINITY),
+                );
+                if response.changed() {
+                    rows.dirty = true;
+                }
+            });
+
+            if rows.dirty {
+                rebuild_rows(&mut rows, &stdb, &ui_state);
+            }
+            ui.label(format!("{} object(s)", rows.rows.len()));
+            ui.add_space(4.0);
+
+            if render_header(ui, &mut ui_state) {
+                rebuild_rows(&mut rows, &stdb, &ui_state);
+            }
+            ui.separator();
+
+            // Scroll to objects selected in the viewport, but not to rows just clicked here.
+            let selected_elsewhere =
+                selection.is_changed() && selection.ids().last().copied() != clicked_row.0;
+            let scroll_to = selected_elsewhere
+                .then(|| selection.ids().last())
+                .flatten()
+                .and_then(|id| rows.rows.iter().position(|row| row.id == *id));
+            clicked_row.0 = None;
+
+            let mut scroll_area = egui::ScrollArea::vertical().auto_shrink([false, false]);
+            if let Some(index) = scroll_to {
+                let offset = index as f32 * ROW_HEIGHT - ui.available_height() * 0.5;
+                scroll_area = scroll_area.vertical_scroll_offset(offset.max(0.0));
+            }
+
+            ui.spacing_mut().item_spacing.y = 0.0;
+            scroll_area.show_rows(ui, ROW_HEIGHT, rows.rows.len(), |ui, range| {
+                for row in &rows.rows[range] {
+                    let holder = locked_by_other(&stdb, row.id);
+                    let mut response =
+                        render_row(ui, row, selection.contains(row.id), holder.is_some());
+                    if let Some(owner) = holder {
+                        response = response
+                            .on_hover_text(format!("Locked by {}", editor_name(&stdb, owner)));
+                    }
+
+                    if response.double_clicked() {
+                        focus_on(&stdb, row.id, &mut focus);
+                    } else if response.clicked() {
+                        let modifiers = ui.input(|input| input.modifiers);
+                        if modifiers.command {
+                            selection.toggle(row.id);
+                        } else if modifiers.shift {
+                            selection.add(row.id);
+                        } else {
+                            selection.select_single(row.id);
+                        }
+                        clicked_row.0 = Some(row.id);
+                    }
+                }
+            });
+        });
+}
+
+/// Sort buttons for each column. Returns whether the sort order changed.
+fn render_header(ui: &mut egui::Ui, ui_state: &mut OutlinerUiState) -> bool {
+    let mut changed = false;
+    ui.horizontal(|ui| {
+        for (column, width) in [
+            (SortColumn::Id, ID_COLUMN_WIDTH),
+            (
+                SortColumn::Asset,
+                ui.available_width() - ID_COLUMN_WIDTH - COLLISION_COLUMN_WIDTH,
+            ),
+            (SortColumn::Collision, COLLISION_COLUMN_WIDTH),
+        ] {
+            let arrow = match (ui_state.sort == column, ui_state.descending) {
+                (false, _) => "",
+                (true, false) => " ⬆",
+                (true, true) => " ⬇",
+            };
+            let button = egui::Button::new(format!("{}{}", column.label(), arrow)).frame(false);
+            if ui.add_sized([width.max(0.0), ROW_HEIGHT], button).clicked() {
+                if ui_state.sort == column {
+                    ui_state.descending = !ui_state.descending;
+                } else {
+                    ui_state.sort = column;
+                    ui_state.descending = false;
+                }
+                changed = true;
+            }
+        }
+    });
+    changed
+}
+
+/// One list row as a single clickable rect with its columns painted on top.
+fn render_row(
+    ui: &mut egui::Ui,
+    row: &OutlinerRow,
+    selected: bool,
+    locked: bool,
+) -> egui::Response {
+    let (rect, response) = ui.allocate_exact_size(
+        egui::vec2(ui.available_width(), ROW_HEIGHT),
+        egui::Sense::click(),
+    );
+
+    let visuals = ui.visuals();
+    if selected {
+        ui.painter()
+            .rect_filled(rect, 0.0, visuals.selection.bg_fill);
+    } else if response.hovered() {
+        ui.painter()
+            .rect_filled(rect, 0.0, visuals.widgets.hovered.weak_bg_fill);
+    }
+
+    let color = if selected {
+        visuals.selection.stroke.color
+    } else {
+        visuals.text_color()
+    };
+    let font = egui::FontId::monospace(12.0);
+    let y = rect.center().y;
+
+    ui.painter().text(
+        egui::pos2(rect.left() + 4.0, y),
+        egui::Align2::LEFT_CENTER,
+        row.id.to_string(),
+        font.clone(),
+        color,
+    );
+    if locked {
+        ui.painter().text(
+            egui::pos2(rect.left() + ID_COLUMN_WIDTH - 4.0, y),
+            egui::Align2::RIGHT_CENTER,
+            "🔒",
+            font.clone(),
+            color,
+        );
+    }
+
+    // Long asset paths are clipped at the collision column instead of overlapping it.
+    let asset_rect = egui::Rect::from_x_y_ranges(
+        rect.left() + ID_COLUMN_WIDTH..=rect.right() - COLLISION_COLUMN_WIDTH - 4.0,
+        rect.y_range(),
+    );
+    ui.painter().with_clip_rect(asset_rect).text(
+        egui::pos2(asset_rect.left(), y),
+        egui::Align2::LEFT_CENTER,
+        &row.asset,
+        font.clone(),
+        color,
+    );
+
+    ui.painter().text(
+        egui::pos2(rect.right() - COLLISION_COLUMN_WIDTH, y),
+        egui::Align2::LEFT_CENTER,
+        row.collision,
+        font,
+        color,
+    );
+
+    response
+}
+
+fn rebuild_rows(rows: &mut OutlinerRows, stdb: &SpacetimeDB, ui_state: &OutlinerUiState) {
+    let filter = ui_state.filter.trim().to_lowercase();
+
+    rows.rows = stdb
+        .db()
+        .world_object()
+        .iter()
+        .map(|object| OutlinerRow {
+            id: object.id,
+            asset: asset_label(&object.asset),
+            collision: collision_label(&object.collision_shape),
+        })
+        .filter(|row| {
+            filter.is_empty()
+                || row.id.to_string().contains(&filter)
+                || row.asset.to_lowercase().contains(&filter)
+                || row.collision.to_lowercase().contains(&filter)
+        })
+        .collect();
+
+    match ui_state.sort {
+        SortColumn::Id => rows.rows.sort_by_key(|row| row.id),
+        SortColumn::Asset => rows
+            .rows
+            .sort_by(|a, b| a.asset.cmp(&b.asset).then(a.id.cmp(&b.id))),
+        SortColumn::Collision => rows
+            .rows
+            .sort_by(|a, b| a.collision.cmp(b.collision).then(a.id.cmp(&b.id))),
+    }
+    if ui_state.descending {
+        rows.rows.reverse();
+    }
+
+    rows.dirty = false;
+}
+
+fn focus_on(stdb: &SpacetimeDB, id: u64, focus: &mut MessageWriter<FocusCamera>) {
+    let Some(object) = stdb.db().world_object().id().find(&id) else {
+        return;
+    };
+
+    let scale = Vec3::from(object.scale).abs().max_element();
+    focus.write(FocusCamera {
+        target: object.translation.into(),
+        distance: (scale * FOCUS_DISTANCE_PER_SCALE).max(MIN_FOCUS_DISTANCE),
+    });
+}
+
+fn collision_label(shape: &CollisionShape) -> &'static str {
+    match shape {
+        CollisionShape::None => "None",
+        CollisionShape::Cuboid(_) => "Cuboid",
+        CollisionShape::Ball(_) => "Ball",
+        CollisionShape::Capsule(_) => "Capsule",
+        CollisionShape::Heightfield(_) => "Heightfield",
+        CollisionShape::ConvexHull(_) => "Convex hull",
+    }
+}
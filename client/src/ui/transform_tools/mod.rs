@@ -5,22 +5,29 @@
 //!
 //! This module provides:
 //! - `TransformToolMode` enum (Translate/Rotate/Scale)
-//! - `TransformTool` resource: `{ selected_tool, is_active }`
+//! - `TransformSpace` enum (World/Local), the orientation the tools work in
+//! - `TransformTool` resource: `{ selected_tool, space, is_active }`
 //! - an egui toolbar renderer suitable for placing in the top app bar
 //! - W/E/R hotkeys to switch mode (disabled while a drag interaction is active)
+//! - snapping settings (`snapping`) applied by the drags
 //!
 //! Tool locking:
 //! - While a transform drag interaction is active, switching tools is disabled.
 //! - The interaction system should set `TransformTool.is_active = true` on `DragStart`
 //!   and set it back to `false` on `DragEnd` (after saving).
 
+mod snapping;
+
 use bevy::prelude::*;
 use bevy_egui::{EguiContexts, egui};
 
 use crate::flycam::FlyCamActive;
 
+pub use snapping::{SnapSettings, Snapping, render_snapping};
+
 pub(super) fn plugin(app: &mut App) {
     app.init_resource::<TransformTool>();
+    app.init_resource::<SnapSettings>();
 
     // Hotkeys are handled in Update so it works regardless of egui pass scheduling.
     app.add_systems(Update, handle_hotkeys);
@@ -53,9 +60,29 @@ impl TransformToolMode {
     }
 }
 
+/// Coordinate space the transform tools orient their axes in.
+///
+/// Scaling always happens along the object's own axes, so it ignores this.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TransformSpace {
+    #[default]
+    World,
+    Local,
+}
+
+impl TransformSpace {
+    pub fn label(self) -> &'static str {
+        match self {
+            TransformSpace::World => "World",
+            TransformSpace::Local => "Local",
+        }
+    }
+}
+
 /// Global editor state for transform tools.
 ///
 /// `selected_tool` is the currently selected tool.
+/// `space` is the orientation (World/Local) of the gizmo axes and axis-constrained drags.
 /// `is_active` means "a drag interaction is currently using the selected tool"
 /// and tool switching must be disabled until the interaction ends.
 ///
@@ -65,6 +92,7 @@ impl TransformToolMode {
 #[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
 pub struct TransformTool {
     pub selected_tool: TransformToolMode,
+    pub space: TransformSpace,
     pub is_active: bool,
 }
 
@@ -72,6 +100,7 @@ impl Default for TransformTool {
     fn default() -> Self {
         Self {
             selected_tool: TransformToolMode::Translate,
+            space: TransformSpace::World,
             is_active: false,
         }
     }
@@ -90,6 +119,10 @@ pub fn render_toolbar(ui: &mut egui::Ui, tool: &mut TransformTool) {
         tool_button(ui, tool, TransformToolMode::Translate, disabled);
         tool_button(ui, tool, TransformToolMode::Rotate, disabled);
         tool_button(ui, tool, TransformToolMode::Scale, disabled);
+
+        ui.separator();
+        space_button(ui, tool, TransformSpace::World, disabled);
+        space_button(ui, tool, TransformSpace::Local, disabled);
     });
 }
 
@@ -109,6 +142,23 @@ fn tool_button(
     }
 }
 
+fn space_button(
+    ui: &mut egui::Ui,
+    tool: &mut TransformTool,
+    space: TransformSpace,
+    disabled: bool,
+) {
+    let selected = tool.space == space;
+
+    let resp = ui.add_enabled(
+        !disabled,
+        egui::Button::new(space.label()).selected(selected),
+    );
+    if resp.clicked() {
+        tool.space = space;
+    }
+}
+
 /// Handle W/E/R hotkeys to switch the selected transform tool.
 ///
 /// Disabled while:
@@ -0,0 +1,130 @@
+//! Snapping increments for the transform tools.
+//!
+//! Each kind of snapping has its own toggle and step, set from the app bar. Holding Alt during
+//! a drag inverts the toggles: snapping turns on if it was off, and off if it was on. Alt
+//! because Shift and Ctrl already change the selection when a drag starts.
+
+use bevy::{ecs::system::SystemParam, prelude::*};
+use bevy_egui::egui;
+
+use crate::infinite_grid::GRID_MINOR_SPACING;
+
+/// Keys that invert the snapping toggles while held.
+const INVERT_KEYS: [KeyCode; 2] = [KeyCode::AltLeft, KeyCode::AltRight];
+
+/// One snapping toggle and the increment values snap to.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SnapIncrement {
+    pub enabled: bool,
+    pub step: f32,
+}
+
+/// Snapping settings shared by the object drags and the gizmo.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct SnapSettings {
+    /// Translation increment, in meters.
+    pub translate: SnapIncrement,
+    /// Rotation increment, in degrees.
+    pub rotate: SnapIncrement,
+    /// Scale increment, as a scale factor.
+    pub scale: SnapIncrement,
+}
+
+impl Default for SnapSettings {
+    fn default() -> Self {
+        Self {
+            translate: SnapIncrement {
+                enabled: false,
+                step: GRID_MINOR_SPACING,
+            },
+            rotate: SnapIncrement {
+                enabled: false,
+                step: 15.0,
+            },
+            scale: SnapIncrement {
+                enabled: false,
+                step: 0.1,
+            },
+        }
+    }
+}
+
+/// Snaps drag results according to `SnapSettings` and the held modifier.
+#[derive(SystemParam)]
+pub struct Snapping<'w> {
+    settings: Res<'w, SnapSettings>,
+    keys: Res<'w, ButtonInput<KeyCode>>,
+}
+
+impl Snapping<'_> {
+    /// Snap the coordinates of `point` along the `axes` of the frame `rotation`.
+    ///
+    /// With an identity rotation this lines the point up with the grid.
+    pub fn translation(&self, point: Vec3, rotation: Quat, axes: BVec3) -> Vec3 {
+        let Some(step) = self.step(self.settings.translate) else {
+            return point;
+        };
+
+        let local = rotation.inverse() * point;
+        rotation * Vec3::select(axes, (local / step).round() * step, local)
+    }
+
+    /// Snap an angle in radians.
+    pub fn rotation(&self, angle: f32) -> f32 {
+        match self.step(self.settings.rotate) {
+            Some(step) => snap(angle, step.to_radians()),
+            None => angle,
+        }
+    }
+
+    /// Snap an absolute scale value, so scaled objects land on multiples of the step.
+    pub fn scale(&self, value: f32) -> f32 {
+        match self.step(self.settings.scale) {
+            Some(step) => snap(value, step),
+            None => value,
+        }
+    }
+
+    fn step(&self, increment: SnapIncrement) -> Option<f32> {
+        let inverted = self.keys.any_pressed(INVERT_KEYS);
+        (increment.enabled != inverted && increment.step > 0.0).then_some(increment.step)
+    }
+}
+
+fn snap(value: f32, step: f32) -> f32 {
+    (value / step).round() * step
+}
+
+/// Render the snapping toggles and increments. Call this from the top app bar, next to the tools.
+pub fn render_snapping(ui: &mut egui::Ui, snap: &mut SnapSettings) {
+    ui.horizontal(|ui| {
+        ui.spacing_mut().item_spacing.x = 4.0;
+
+        increment_control(ui, "Grid", &mut snap.translate, " m", 0.01..=100.0, 0.05);
+        ui.add_space(4.0);
+        increment_control(ui, "Angle", &mut snap.rotate, "°", 0.5..=180.0, 0.5);
+        ui.add_space(4.0);
+        increment_control(ui, "Scale", &mut snap.scale, "", 0.01..=10.0, 0.01);
+    });
+}
+
+fn increment_control(
+    ui: &mut egui::Ui,
+    label: &str,
+    increment: &mut SnapIncrement,
+    suffix: &str,
+    range: std::ops::RangeInclusive<f32>,
+    speed: f64,
+) {
+    ui.toggle_value(&mut increment.enabled, label)
+        .on_hover_text(format!(
+            "Snap {} while dragging. Hold Alt to invert.",
+            label.to_lowercase()
+        ));
+    ui.add(
+        egui::DragValue::new(&mut increment.step)
+            .range(range)
+            .speed(speed)
+            .suffix(suffix),
+    );
+}
@@ -0,0 +1,322 @@
+//! Undo/redo for editor edits (Ctrl+Z / Ctrl+Shift+Z, or Ctrl+Y to redo).
+//!
+//! Every committed edit becomes part of an undo step recording each touched row before and
+//! after the change. Calls issued in the same frame (e.g. the moves and rotations of one
+//! multi-object drag) share a step. Undoing a step issues the reducer calls that put every row
+//! back; deleted rows come back with their original ids through `restore_object`.
+//!
+//! Other editors can change the same rows in the meantime. Before undoing or redoing, every
+//! row in the step must still look exactly like this editor left it; otherwise the step is
+//! dropped from the history with a warning instead of overwriting the other change.
+
+use core::time::Duration;
+
+use bevy::{platform::collections::HashMap, prelude::*};
+use bevy_egui::{EguiContexts, EguiPrimaryContextPass, egui};
+use spacetimedb_sdk::Table;
+
+use crate::{
+    flycam::FlyCamActive,
+    module_bindings::{WorldObject, WorldObjectTableAccess},
+    pending_ops::{
+        EditorOps, Expectation, OpOrigin, OpOutcome, OpResolved, contents_match, rows_match,
+    },
+    snapshot::SyncState,
+    spacetimedb::SpacetimeDB,
+    ui::transform_tools::TransformTool,
+};
+
+/// Oldest steps are forgotten beyond this many.
+const UNDO_LIMIT: usize = 200;
+
+/// How long undo/redo warnings stay on screen.
+const NOTICE_DURATION: Duration = Duration::from_secs(4);
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<UndoHistory>();
+    app.add_systems(Update, (record_resolved_ops, handle_hotkeys).chain());
+    app.add_systems(EguiPrimaryContextPass, render_notice);
+}
+
+/// How one row changed. `None` means the row didn't exist.
+#[derive(Debug, Clone)]
+pub struct RowChange {
+    pub before: Option<WorldObject>,
+    pub after: Option<WorldObject>,
+}
+
+/// Everything one gesture changed, keyed by row id.
+#[derive(Debug, Clone)]
+pub struct UndoStep {
+    /// `FrameCount` the gesture's calls were issued in.
+    frame: u32,
+    pub changes: HashMap<u64, RowChange>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    Undo,
+    Redo,
+}
+
+impl Direction {
+    fn label(self) -> &'static str {
+        match self {
+            Direction::Undo => "undo",
+            Direction::Redo => "redo",
+        }
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct UndoHistory {
+    undo: Vec<UndoStep>,
+    redo: Vec<UndoStep>,
+    /// Warning to show, and `Time::elapsed` until which it's shown.
+    notice: Option<(String, Duration)>,
+}
+
+impl UndoHistory {
+    /// Add a committed change to the step of the gesture it belongs to.
+    fn record(&mut self, frame: u32, id: u64, change: RowChange) {
+        if self.undo.last().is_none_or(|step| step.frame != frame) {
+            self.undo.push(UndoStep {
+                frame,
+                changes: HashMap::default(),
+            });
+            if self.undo.len() > UNDO_LIMIT {
+                self.undo.remove(0);
+            }
+        }
+
+        // A new edit makes the redo steps meaningless.
+        self.redo.clear();
+
+        let step = self.undo.last_mut().expect("a step was just pushed");
+        step.changes
+            .entry(id)
+            .and_modify(|existing| existing.after = change.after.clone())
+            .or_insert(change);
+    }
+
+    fn notify(&mut self, message: String, now: Duration) {
+        eprintln!("{message}");
+        self.notice = Some((message, now + NOTICE_DURATION));
+    }
+}
+
+/// Turn committed edits into undo steps, and report undo/redo calls the DB refused.
+fn record_resolved_ops(
+    stdb: SpacetimeDB,
+    time: Res<Time>,
+    mut resolved: MessageReader<OpResolved>,
+    mut history: ResMut<UndoHistory>,
+) {
+    let table = stdb.db().world_object();
+
+    for OpResolved { op, outcome } in resolved.read() {
+        if let OpOutcome::Failed(message) = outcome {
+            if op.origin != OpOrigin::Edit {
+                history.notify(format!("Undo/redo failed: {message}"), time.elapsed());
+            }
+            continue;
+        }
+
+        // Undo/redo calls were already moved between the stacks when they were issued.
+        if op.origin != OpOrigin::Edit {
+            continue;
+        }
+
+        match &op.expectation {
+            Expectation::Inserted { object, .. } => {
+                // The DB picks the id. Ids only grow, so the newest matching row is ours.
+                let inserted = table
+                    .iter()
+                    .filter(|row| contents_match(row, object))
+                    .max_by_key(|row| row.id);
+                if let Some(row) = inserted {
+                    let change = RowChange {
+                        before: None,
+                        after: Some(row.clone()),
+                    };
+                    history.record(op.frame, row.id, change);
+                }
+            }
+            Expectation::Restored { object } => {
+                let change = RowChange {
+                    before: None,
+                    after: Some(object.clone()),
+                };
+                history.record(op.frame, object.id, change);
+            }
+            Expectation::Translation { .. }
+            | Expectation::Rotation { .. }
+            | Expectation::Scale { .. }
+            | Expectation::Collision { .. }
+            | Expectation::Deleted { .. } => {
+                for before in &op.before {
+                    let change = RowChange {
+                        before: Some(before.clone()),
+                        after: table.id().find(&before.id),
+                    };
+                    history.record(op.frame, before.id, change);
+                }
+            }
+        }
+    }
+}
+
+/// Ctrl+Z undoes, Ctrl+Shift+Z and Ctrl+Y redo.
+///
+/// Gated like the other editor hotkeys: never while flycam is active, mid-drag, or while
+/// egui has keyboard focus (text fields have their own undo).
+#[allow(clippy::too_many_arguments)]
+fn handle_hotkeys(
+    keys: Res<ButtonInput<KeyCode>>,
+    flycam_active: Res<FlyCamActive>,
+    tool: Res<TransformTool>,
+    sync: Res<SyncState>,
+    time: Res<Time>,
+    stdb: SpacetimeDB,
+    mut contexts: EguiContexts,
+    mut history: ResMut<UndoHistory>,
+    mut ops: EditorOps,
+) {
+    let ctrl = keys.any_pressed([
+        KeyCode::ControlLeft,
+        KeyCode::ControlRight,
+        KeyCode::SuperLeft,
+        KeyCode::SuperRight,
+    ]);
+    let shift = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+
+    let direction = if ctrl && keys.just_pressed(KeyCode::KeyZ) {
+        if shift {
+            Direction::Redo
+        } else {
+            Direction::Undo
+        }
+    } else if ctrl && keys.just_pressed(KeyCode::KeyY) {
+        Direction::Redo
+    } else {
+        return;
+    };
+
+    if tool.is_active || flycam_active.0 || !sync.allows_edits() {
+        return;
+    }
+
+    if let Ok(ctx) = contexts.ctx_mut()
+        && ctx.wants_keyboard_input()
+    {
+        return;
+    }
+
+    let stack = match direction {
+        Direction::Undo => &mut history.undo,
+        Direction::Redo => &mut history.redo,
+    };
+    let Some(step) = stack.pop() else {
+        return;
+    };
+
+    if let Some(id) = find_conflict(&stdb, &step, direction) {
+        history.notify(
+            format!(
+                "Can't {} this step: object {} was changed by someone else since. \
+                 The step was removed from the history.",
+                direction.label(),
+                id
+            ),
+            time.elapsed(),
+        );
+        return;
+    }
+
+    let origin = match direction {
+        Direction::Undo => OpOrigin::Undo,
+        Direction::Redo => OpOrigin::Redo,
+    };
+    ops.replaying(origin, |ops| {
+        let mut deleted = Vec::new();
+        for (id, change) in &step.changes {
+            let (from, to) = match direction {
+                Direction::Undo => (&change.after, &change.before),
+                Direction::Redo => (&change.before, &change.after),
+            };
+            match (from, to) {
+                (Some(_), None) => deleted.push(*id),
+                (None, Some(to)) => ops.restore_object(to.clone()),
+                (Some(from), Some(to)) => revert_fields(ops, from, to),
+                (None, None) => {}
+            }
+        }
+
+        match deleted.as_slice() {
+            [] => {}
+            [id] => ops.delete_object(*id),
+            ids => ops.delete_objects(ids.to_vec()),
+        }
+    });
+
+    match direction {
+        Direction::Undo => history.redo.push(step),
+        Direction::Redo => history.undo.push(step),
+    }
+}
+
+/// The first row in `step` that no longer looks the way the step left it, if any.
+fn find_conflict(stdb: &SpacetimeDB, step: &UndoStep, direction: Direction) -> Option<u64> {
+    let table = stdb.db().world_object();
+
+    step.changes.iter().find_map(|(id, change)| {
+        let expected = match direction {
+            Direction::Undo => &change.after,
+            Direction::Redo => &change.before,
+        };
+        let current = table.id().find(id);
+
+        let unchanged = match (expected, &current) {
+            (Some(expected), Some(current)) => rows_match(expected, current),
+            (None, None) => true,
+            _ => false,
+        };
+        (!unchanged).then_some(*id)
+    })
+}
+
+/// Send the calls that take an existing row from `from` to `to`.
+fn revert_fields(ops: &mut EditorOps, from: &WorldObject, to: &WorldObject) {
+    if from.translation != to.translation {
+        ops.move_object(to.id, to.translation.clone());
+    }
+    if from.rotation != to.rotation {
+        ops.rotate_object(to.id, to.rotation.clone());
+    }
+    if from.scale != to.scale {
+        ops.scale_object(to.id, to.scale.clone());
+    }
+    if from.collision_shape != to.collision_shape {
+        ops.set_collision_shape(to.id, to.collision_shape.clone());
+    }
+}
+
+fn render_notice(mut contexts: EguiContexts, time: Res<Time>, history: Res<UndoHistory>) {
+    let Some((message, until)) = &history.notice else {
+        return;
+    };
+    if time.elapsed() > *until {
+        return;
+    }
+
+    let ctx = contexts.ctx_mut().expect("to get primary egui context");
+    egui::Area::new(egui::Id::new("undo_notice"))
+        .anchor(egui::Align2::CENTER_BOTTOM, egui::vec2(0.0, -24.0))
+        .interactable(false)
+        .show(ctx, |ui| {
+            egui::Frame::popup(ui.style()).show(ui, |ui| {
+                ui.set_max_width(420.0);
+                ui.colored_label(egui::Color32::from_rgb(230, 160, 60), message);
+            });
+        });
+}
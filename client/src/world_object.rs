@@ -1,14 +1,17 @@
 use crate::{
+    colliders::ColliderWireframe,
     flycam::FlyCamActive,
-    module_bindings::{
-        AssetKind, CollisionShape, WorldObject, insert_object, move_object, rotate_object,
-        scale_object,
-    },
+    locks::{LockRequests, editor_name, first_locked_by_other},
+    module_bindings::{AssetKind, CollisionShape, PrimitiveShape, WorldObject},
+    pending_ops::EditorOps,
+    selection::{self, SelectMode, Selection},
+    snapshot::SyncState,
     spacetimedb::SpacetimeDB,
-    ui::transform_tools::{TransformTool, TransformToolMode},
+    ui::transform_tools::{Snapping, TransformSpace, TransformTool, TransformToolMode},
 };
-use bevy::prelude::*;
-use bevy_spacetimedb::ReadInsertMessage;
+use bevy::{platform::collections::HashMap, prelude::*};
+use bevy_egui::EguiContexts;
+use bevy_spacetimedb::{ReadDeleteMessage, ReadInsertMessage, ReadUpdateMessage};
 
 #[derive(Resource, Default)]
 struct DragMoveState {
@@ -19,11 +22,71 @@ struct DragMoveState {
     /// - has normal = camera forward at drag start
     plane_origin: Option<Vec3>,
     plane_normal: Option<Vec3>,
+    /// Every selected object's entity, row id and transform at drag start.
+    start: Vec<(Entity, u64, Transform)>,
+    /// Mean position of the selection at drag start; rotations orbit around it.
+    pivot: Vec3,
+    /// Yaw and pitch accumulated since drag start (Rotate tool), in radians.
+    /// Kept as angles rather than a rotation so each can snap on its own.
+    yaw: f32,
+    pitch: f32,
+    /// Uniform scale change accumulated since drag start (Scale tool).
+    scale_delta: f32,
+}
+
+impl DragMoveState {
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// Shared material for world objects rendered from a `PrimitiveShape`.
+///
+/// Materials are out of scope for the DB schema, so every primitive uses the same neutral surface.
+#[derive(Resource)]
+struct PrimitiveMaterial(Handle<StandardMaterial>);
+
+/// Lookup from a `WorldObject` row id to the entity rendering it.
+///
+/// Maintained by the insert/update/delete systems so row changes can be applied
+/// without scanning every `ObjectId` entity.
+#[derive(Resource, Default, Debug)]
+pub struct WorldObjectIndex(HashMap<u64, Entity>);
+
+impl WorldObjectIndex {
+    pub fn get(&self, id: u64) -> Option<Entity> {
+        self.0.get(&id).copied()
+    }
+
+    /// Every rendered object as `(row id, entity)`, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (u64, Entity)> + '_ {
+        self.0.iter().map(|(id, entity)| (*id, *entity))
+    }
 }
 
 pub(super) fn plugin(app: &mut App) {
     app.init_resource::<DragMoveState>();
-    app.add_systems(Update, (on_insert, spawn_alien_on_key0));
+    app.init_resource::<WorldObjectIndex>();
+    app.add_systems(Startup, setup_primitive_material);
+    app.add_systems(
+        Update,
+        (
+            (on_insert, on_update, on_delete).chain(),
+            spawn_alien_on_key0,
+            delete_selection_on_key,
+        ),
+    );
+}
+
+fn setup_primitive_material(
+    mut commands: Commands,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.insert_resource(PrimitiveMaterial(materials.add(StandardMaterial {
+        base_color: Color::srgb(0.8, 0.8, 0.8),
+        perceptual_roughness: 0.8,
+        ..default()
+    })));
 }
 
 #[derive(Component)]
@@ -32,51 +95,172 @@ pub struct ObjectId(pub u64);
 fn on_insert(
     mut commands: Commands,
     mut inserted: ReadInsertMessage<WorldObject>,
+    mut index: ResMut<WorldObjectIndex>,
     asset_server: Res<AssetServer>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    primitive_material: Res<PrimitiveMaterial>,
 ) {
     for msg in inserted.read() {
-        let row = msg.row.clone();
+        let row = &msg.row;
         println!("Inserting WorldObject with ID: {}", row.id);
 
-        let translation = Vec3::new(row.translation.x, row.translation.y, row.translation.z);
-        let rotation = Quat::from_xyzw(
-            row.rotation.x,
-            row.rotation.y,
-            row.rotation.z,
-            row.rotation.w,
+        // A re-subscribe can deliver a row we already render; replace it rather than duplicating.
+        if let Some(existing) = index.0.remove(&row.id) {
+            commands.entity(existing).despawn();
+        }
+
+        let entity = spawn_world_object(
+            &mut commands,
+            row,
+            &asset_server,
+            &mut meshes,
+            &primitive_material,
         );
-        let scale = Vec3::new(row.scale.x, row.scale.y, row.scale.z);
+        index.0.insert(row.id, entity);
+    }
+}
+
+fn on_update(
+    mut commands: Commands,
+    mut updated: ReadUpdateMessage<WorldObject>,
+    mut index: ResMut<WorldObjectIndex>,
+    mut transforms: Query<&mut Transform, With<ObjectId>>,
+    asset_server: Res<AssetServer>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    primitive_material: Res<PrimitiveMaterial>,
+) {
+    for msg in updated.read() {
+        let row = &msg.new;
+
+        // The asset decides which components (scene vs mesh) the entity carries,
+        // so swapping it is simplest as a full respawn.
+        if msg.old.asset != row.asset || index.get(row.id).is_none() {
+            if let Some(existing) = index.0.remove(&row.id) {
+                commands.entity(existing).despawn();
+            }
+
+            let entity = spawn_world_object(
+                &mut commands,
+                row,
+                &asset_server,
+                &mut meshes,
+                &primitive_material,
+            );
+            index.0.insert(row.id, entity);
+            continue;
+        }
 
-        let transform = Transform {
-            translation,
-            rotation,
-            scale,
+        let Some(entity) = index.get(row.id) else {
+            continue;
         };
+        if let Ok(mut transform) = transforms.get_mut(entity) {
+            *transform = row_transform(row);
+        }
+        if msg.old.collision_shape != row.collision_shape {
+            commands
+                .entity(entity)
+                .insert(ColliderWireframe::new(&row.collision_shape));
+        }
+    }
+}
 
-        // If an asset path is provided, try to load it as a glTF scene (common for .gltf/.glb).
-        // The stored path should be relative to Bevy's asset root, e.g. "FlightHelmet.gltf".
-        match row.asset {
-            AssetKind::Path(path) => {
-                // For glTF files, a good default is loading the default scene.
-                // Bevy supports the "#Scene0" suffix for glTF scenes.
-                let scene_handle: Handle<Scene> = asset_server.load(format!("{path}#Scene0"));
-
-                commands
-                    .spawn((SceneRoot(scene_handle), transform, ObjectId(row.id)))
-                    .observe(on_drag_start)
-                    .observe(on_drag_transform)
-                    .observe(on_drag_end);
-            }
-            _ => {
-                todo!("implement primitive shapes")
-            }
+fn on_delete(
+    mut commands: Commands,
+    mut deleted: ReadDeleteMessage<WorldObject>,
+    mut index: ResMut<WorldObjectIndex>,
+) {
+    for msg in deleted.read() {
+        println!("Deleting WorldObject with ID: {}", msg.row.id);
+
+        if let Some(entity) = index.0.remove(&msg.row.id) {
+            commands.entity(entity).despawn();
         }
+    }
+}
 
-        continue;
+/// The `Transform` stored on a `WorldObject` row.
+pub fn row_transform(row: &WorldObject) -> Transform {
+    Transform {
+        translation: row.translation.clone().into(),
+        rotation: row.rotation.clone().into(),
+        scale: row.scale.clone().into(),
     }
 }
 
-fn spawn_alien_on_key0(keys: Res<ButtonInput<KeyCode>>, stdb: SpacetimeDB) {
+/// Spawn the entity that renders `row`, with the drag observers attached.
+fn spawn_world_object(
+    commands: &mut Commands,
+    row: &WorldObject,
+    asset_server: &AssetServer,
+    meshes: &mut Assets<Mesh>,
+    primitive_material: &PrimitiveMaterial,
+) -> Entity {
+    let mut entity = commands.spawn((
+        row_transform(row),
+        ObjectId(row.id),
+        ColliderWireframe::new(&row.collision_shape),
+    ));
+
+    // If an asset path is provided, try to load it as a glTF scene (common for .gltf/.glb).
+    // The stored path should be relative to Bevy's asset root, e.g. "FlightHelmet.gltf".
+    match &row.asset {
+        AssetKind::Path(path) => {
+            // For glTF files, a good default is loading the default scene.
+            // Bevy supports the "#Scene0" suffix for glTF scenes.
+            let scene_handle: Handle<Scene> = asset_server.load(format!("{path}#Scene0"));
+            entity.insert(SceneRoot(scene_handle));
+        }
+        AssetKind::PrimitiveShape(shape) => {
+            entity.insert((
+                Mesh3d(meshes.add(primitive_mesh(shape))),
+                MeshMaterial3d(primitive_material.0.clone()),
+            ));
+        }
+    }
+
+    entity
+        .observe(selection::on_click)
+        .observe(on_drag_start)
+        .observe(on_drag_transform)
+        .observe(on_drag_end)
+        .id()
+}
+
+/// Build a render mesh for a primitive shape in the object's local space.
+///
+/// Dimensions come straight from the shape (half extents, radius, segment endpoints);
+/// the row's `scale` is applied by the entity `Transform` like any other asset.
+fn primitive_mesh(shape: &PrimitiveShape) -> Mesh {
+    match shape {
+        PrimitiveShape::Cuboid(cuboid) => {
+            let half_extents: Vec3 = cuboid.half_extents.clone().into();
+            Cuboid::from_size(half_extents * 2.0).into()
+        }
+        PrimitiveShape::Ball(ball) => Sphere::new(ball.radius).mesh().uv(32, 18),
+        PrimitiveShape::Capsule(capsule) => {
+            let a: Vec3 = capsule.segment.a.clone().into();
+            let b: Vec3 = capsule.segment.b.clone().into();
+            let axis = b - a;
+            let length = axis.length();
+
+            // Bevy's capsule is Y-aligned and centered on the origin, so move it onto the segment.
+            let orientation = if length > f32::EPSILON {
+                Quat::from_rotation_arc(Vec3::Y, axis / length)
+            } else {
+                Quat::IDENTITY
+            };
+
+            Capsule3d::new(capsule.radius, length)
+                .mesh()
+                .build()
+                .transformed_by(
+                    Transform::from_translation((a + b) * 0.5).with_rotation(orientation),
+                )
+        }
+    }
+}
+
+fn spawn_alien_on_key0(keys: Res<ButtonInput<KeyCode>>, mut ops: EditorOps) {
     if !keys.just_pressed(KeyCode::Digit0) {
         return;
     }
@@ -106,15 +290,55 @@ fn spawn_alien_on_key0(keys: Res<ButtonInput<KeyCode>>, stdb: SpacetimeDB) {
         collision_shape: CollisionShape::None,
     };
 
-    let _ = stdb.reducers().insert_object(object);
+    ops.insert_object(object);
 }
 
+/// Delete the current selection with Delete/Backspace.
+///
+/// Gated the same way as the transform tool hotkeys: never while flycam is active,
+/// while egui wants input, or mid-drag.
+fn delete_selection_on_key(
+    keys: Res<ButtonInput<KeyCode>>,
+    flycam_active: Res<FlyCamActive>,
+    tool: Res<TransformTool>,
+    selection: Res<Selection>,
+    mut contexts: EguiContexts,
+    mut ops: EditorOps,
+) {
+    if !keys.any_just_pressed([KeyCode::Delete, KeyCode::Backspace]) {
+        return;
+    }
+
+    if tool.is_active || flycam_active.0 || selection.is_empty() {
+        return;
+    }
+
+    // Backspace in a text field must edit the text, not the world.
+    if let Ok(ctx) = contexts.ctx_mut()
+        && (ctx.wants_keyboard_input() || ctx.wants_pointer_input())
+    {
+        return;
+    }
+
+    match selection.ids() {
+        [id] => ops.delete_object(*id),
+        ids => ops.delete_objects(ids.to_vec()),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 fn on_drag_start(
     drag: On<Pointer<DragStart>>,
-    objects: Query<&Transform>,
+    objects: Query<(&Transform, &ObjectId)>,
+    index: Res<WorldObjectIndex>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut selection: ResMut<Selection>,
     tool: ResMut<TransformTool>,
     flycam_active: Res<FlyCamActive>,
+    sync: Res<SyncState>,
     camera: Query<(&Camera, &GlobalTransform), With<crate::flycam::FlyCam>>,
+    stdb: SpacetimeDB,
+    mut locks: ResMut<LockRequests>,
     mut move_state: ResMut<DragMoveState>,
 ) {
     // Never begin a transform interaction while flycam is active.
@@ -122,24 +346,47 @@ fn on_drag_start(
         return;
     }
 
+    // The drag would only end in a reducer call that gets refused, so don't move anything locally.
+    if !sync.allows_edits() {
+        return;
+    }
+
+    let Ok((object_tf, object_id)) = objects.get(drag.entity) else {
+        return;
+    };
+
+    // Dragging an unselected object selects it first (keeping the rest with Shift/Ctrl),
+    // so the tools always act on the selection rather than on the raw drag target.
+    if !selection.contains(object_id.0) {
+        match SelectMode::from_keys(&keys) {
+            SelectMode::Replace => selection.select_single(object_id.0),
+            SelectMode::Add | SelectMode::Toggle => selection.add(object_id.0),
+        }
+    }
+
+    // Another editor is working on part of the selection; its edits win until it lets go.
+    if let Some((id, owner)) = first_locked_by_other(&stdb, selection.ids()) {
+        eprintln!("Object {id} is locked by {}", editor_name(&stdb, owner));
+        return;
+    }
+    locks.drag = selection.ids().to_vec();
+
     // Lock tool switching for the duration of the drag gesture.
     // We don't allow changing selected tool while active, so `selected_tool` is effectively the locked tool.
     let mut tool = tool;
     tool.is_active = true;
 
     // Reset move state each drag start.
-    move_state.offset = None;
-    move_state.plane_origin = None;
-    move_state.plane_normal = None;
+    move_state.reset();
+    move_state.start = selected_transforms(&selection, &index, |entity| {
+        objects.get(entity).ok().map(|(transform, _)| *transform)
+    });
+    move_state.pivot = selection_pivot(&move_state.start);
 
     if tool.selected_tool != TransformToolMode::Translate {
         return;
     }
 
-    let Ok(object_tf) = objects.get(drag.entity) else {
-        return;
-    };
-
     // Use the primary flycam camera.
     let Ok((cam, cam_gt)) = camera.single() else {
         return;
@@ -152,7 +399,7 @@ fn on_drag_start(
     };
 
     // View-plane free move:
-    // Plane passes through the object and faces the camera.
+    // Plane passes through the dragged object and faces the camera.
     let plane_origin = object_tf.translation;
     let plane_normal = cam_gt.forward().as_vec3();
 
@@ -175,16 +422,19 @@ fn on_drag_start(
     move_state.plane_normal = Some(plane_normal);
 }
 
+#[allow(clippy::too_many_arguments)]
 fn on_drag_transform(
     drag: On<Pointer<Drag>>,
     mut objects: Query<&mut Transform>,
     tool: Res<TransformTool>,
     flycam_active: Res<FlyCamActive>,
+    sync: Res<SyncState>,
     camera: Query<(&Camera, &GlobalTransform), With<crate::flycam::FlyCam>>,
-    move_state: ResMut<DragMoveState>,
+    snapping: Snapping,
+    mut move_state: ResMut<DragMoveState>,
 ) {
-    // Never manipulate objects while flycam is active.
-    if flycam_active.0 {
+    // Never manipulate objects while flycam is active or the world is out of sync.
+    if flycam_active.0 || !sync.allows_edits() {
         return;
     }
 
@@ -194,32 +444,69 @@ fn on_drag_transform(
         return;
     }
 
-    let Ok(mut transform) = objects.get_mut(drag.entity) else {
+    let Ok(dragged) = objects.get(drag.entity).copied() else {
         return;
     };
 
     let mode = tool.selected_tool;
 
+    // Local space follows the dragged object's axes as they were when the drag started.
+    let dragged_start = move_state
+        .start
+        .iter()
+        .find(|(entity, _, _)| *entity == drag.entity)
+        .map_or(dragged, |(_, _, start)| *start);
+
     // Provided by your drag event
     let delta: Vec2 = drag.delta;
 
+    // Every selected object is placed relative to where it was at drag start,
+    // so rounding and snapping never accumulate across frames.
     match mode {
         TransformToolMode::Rotate => {
             // Tune to taste: radians per pixel.
             let sensitivity = 0.01;
 
             // Turntable:
-            // - horizontal drag => yaw about global up
-            // - vertical drag => pitch about object's local right
+            // - horizontal drag => yaw about up
+            // - vertical drag => pitch about right
+            //
+            // World space uses global up and the camera's horizontal right, so vertical drags
+            // tilt the object towards the viewer. Local space uses the dragged object's axes.
             //
             // NOTE: If your world is Z-up (like Unreal), change Vec3::Y to Vec3::Z.
-            let yaw = -delta.x * sensitivity;
-            let pitch = -delta.y * sensitivity;
-
-            let q_yaw = Quat::from_axis_angle(Vec3::Y, yaw);
-            let q_pitch = Quat::from_axis_angle(transform.right().into(), pitch);
+            move_state.yaw -= delta.x * sensitivity;
+            move_state.pitch -= delta.y * sensitivity;
+
+            let (up, right) = match tool.space {
+                TransformSpace::World => {
+                    let right = camera
+                        .single()
+                        .ok()
+                        .and_then(|(_, cam_gt)| {
+                            cam_gt.right().as_vec3().with_y(0.0).try_normalize()
+                        })
+                        .unwrap_or(Vec3::X);
+                    (Vec3::Y, right)
+                }
+                TransformSpace::Local => (
+                    dragged_start.up().as_vec3(),
+                    dragged_start.right().as_vec3(),
+                ),
+            };
 
-            transform.rotation = (q_yaw * q_pitch) * transform.rotation;
+            let q_yaw = Quat::from_axis_angle(up, snapping.rotation(move_state.yaw));
+            let q_pitch = Quat::from_axis_angle(right, snapping.rotation(move_state.pitch));
+
+            // Multiple objects orbit the selection pivot; a single object rotates in place.
+            let rotation = q_yaw * q_pitch;
+            let pivot = move_state.pivot;
+            for (entity, _, start) in &move_state.start {
+                if let Ok(mut transform) = objects.get_mut(*entity) {
+                    transform.rotation = rotation * start.rotation;
+                    transform.translation = pivot + rotation * (start.translation - pivot);
+                }
+            }
         }
         TransformToolMode::Translate => {
             // View-plane free move (Unreal-like):
@@ -235,7 +522,7 @@ fn on_drag_transform(
                 return;
             };
 
-            let plane_origin = move_state.plane_origin.unwrap_or(transform.translation);
+            let plane_origin = move_state.plane_origin.unwrap_or(dragged.translation);
             let plane_normal = move_state
                 .plane_normal
                 .unwrap_or_else(|| cam_gt.forward().as_vec3());
@@ -254,64 +541,116 @@ fn on_drag_transform(
 
             // If we somehow missed DragStart offset, fall back to snapping the origin to cursor.
             let offset = move_state.offset.unwrap_or(Vec3::ZERO);
-            transform.translation = hit + offset;
+
+            // The dragged object follows the cursor (lined up with the grid, or its own axes in
+            // local space, when snapping); the rest of the selection keeps its offset to it.
+            let snap_frame = match tool.space {
+                TransformSpace::World => Quat::IDENTITY,
+                TransformSpace::Local => dragged_start.rotation,
+            };
+            let target = snapping.translation(hit + offset, snap_frame, BVec3::TRUE);
+            let translation = target - plane_origin;
+            for (entity, _, start) in &move_state.start {
+                if let Ok(mut transform) = objects.get_mut(*entity) {
+                    transform.translation = start.translation + translation;
+                }
+            }
         }
         TransformToolMode::Scale => {
             // Simple uniform scale:
             // - drag right/up increases, left/down decreases
             let sensitivity = 0.01; // scale delta per pixel
-            let ds = (delta.x - delta.y) * sensitivity;
-
-            let mut new_scale = transform.scale + Vec3::splat(ds);
-            // Prevent negative/zero scale
-            new_scale = new_scale.max(Vec3::splat(0.001));
-            transform.scale = new_scale;
+            move_state.scale_delta += (delta.x - delta.y) * sensitivity;
+
+            let scale_delta = Vec3::splat(move_state.scale_delta);
+            for (entity, _, start) in &move_state.start {
+                if let Ok(mut transform) = objects.get_mut(*entity) {
+                    // Snap the resulting scale, like the gizmo does, and keep each component
+                    // away from zero without flipping its sign
+                    transform.scale = (start.scale + scale_delta).map(|component| {
+                        let snapped = snapping.scale(component);
+                        snapped.signum() * snapped.abs().max(0.001)
+                    });
+                }
+            }
         }
     }
 }
 
 fn on_drag_end(
-    drag: On<Pointer<DragEnd>>,
-    objects: Query<(&Transform, &ObjectId)>,
-    stdb: SpacetimeDB,
+    _drag: On<Pointer<DragEnd>>,
+    objects: Query<&Transform>,
+    mut ops: EditorOps,
     tool: ResMut<TransformTool>,
     flycam_active: Res<FlyCamActive>,
+    mut locks: ResMut<LockRequests>,
     mut move_state: ResMut<DragMoveState>,
 ) {
-    // If flycam is active, we shouldn't have been manipulating; ensure we unlock.
+    // The edits (if any) are sent below, before the lock sync releases the objects.
+    locks.drag.clear();
+
+    // If flycam is active or the drag never started, we shouldn't have been manipulating; ensure we unlock.
     let mut tool = tool;
-    if flycam_active.0 {
+    if flycam_active.0 || !tool.is_active {
         tool.is_active = false;
-        move_state.offset = None;
-        move_state.plane_origin = None;
-        move_state.plane_normal = None;
+        move_state.reset();
         return;
     }
 
-    if let Ok((transform, id)) = objects.get(drag.entity) {
-        // Save only what matches the selected/active tool.
-        // Since tool switching is disabled while `is_active == true`,
-        // `selected_tool` is effectively the locked tool for this gesture.
-        match tool.selected_tool {
-            TransformToolMode::Rotate => {
-                let _ = stdb
-                    .reducers()
-                    .rotate_object(id.0, transform.rotation.into());
-            }
-            TransformToolMode::Translate => {
-                let _ = stdb
-                    .reducers()
-                    .move_object(id.0, transform.translation.into());
-            }
-            TransformToolMode::Scale => {
-                let _ = stdb.reducers().scale_object(id.0, transform.scale.into());
-            }
-        }
-    }
+    save_changed_transforms(&mut ops, &move_state.start, &objects);
 
     // Unlock tool switching after we've saved.
     tool.is_active = false;
-    move_state.offset = None;
-    move_state.plane_origin = None;
-    move_state.plane_normal = None;
+    move_state.reset();
+}
+
+/// Each selected object's entity, row id and current transform.
+pub fn selected_transforms(
+    selection: &Selection,
+    index: &WorldObjectIndex,
+    transform_of: impl Fn(Entity) -> Option<Transform>,
+) -> Vec<(Entity, u64, Transform)> {
+    selection
+        .ids()
+        .iter()
+        .filter_map(|id| {
+            let entity = index.get(*id)?;
+            Some((entity, *id, transform_of(entity)?))
+        })
+        .collect()
+}
+
+/// Mean position of the given objects; transform tools rotate and scale around it.
+pub fn selection_pivot(objects: &[(Entity, u64, Transform)]) -> Vec3 {
+    objects
+        .iter()
+        .map(|(_, _, transform)| transform.translation)
+        .sum::<Vec3>()
+        / objects.len().max(1) as f32
+}
+
+/// Send reducers for whatever changed on each object since `start`.
+///
+/// Rotating several objects around their pivot moves them too, so this isn't always just
+/// the component the active tool edits.
+pub fn save_changed_transforms(
+    ops: &mut EditorOps,
+    start: &[(Entity, u64, Transform)],
+    objects: &Query<&Transform>,
+) {
+    for (entity, id, start) in start {
+        let Ok(transform) = objects.get(*entity) else {
+            continue;
+        };
+
+        if transform.translation != start.translation {
+            ops.move_object(*id, transform.translation.into());
+        }
+        if transform.rotation != start.rotation {
+            ops.rotate_object(*id, transform.rotation.into());
+        }
+        if transform.scale != start.scale {
+            ops.scale_object(*id, transform.scale.into());
+        }
+    }
 }
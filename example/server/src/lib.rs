@@ -9,6 +9,8 @@ pub struct Player {
 
 #[spacetimedb::reducer(init)]
 pub fn init(ctx: &ReducerContext) {
+    // Whoever publishes the database may edit the world and grant others editor rights.
+    seed_editor_admin(ctx);
     let _ = ctx.db.world_object().id().find(&1);
 }
 